@@ -0,0 +1,86 @@
+#![feature(test)]
+
+extern crate test;
+
+use std::collections::HashMap;
+
+use aoc2024::{day01, scan::Scan};
+use test::{black_box, Bencher};
+
+// The implementation before parsing was streamed and similarity became a sorted merge.
+mod baseline {
+    use super::*;
+
+    fn parse(input: &str) -> (Vec<isize>, Vec<isize>) {
+        input
+            .lines()
+            .map(|line| {
+                let [l, r]: [isize; 2] = line.ints().unwrap();
+                (l, r)
+            })
+            .unzip()
+    }
+
+    pub fn puzzle1(input: &str) -> isize {
+        let (mut left, mut right) = parse(input);
+        left.sort();
+        right.sort();
+        left.into_iter()
+            .zip(right)
+            .map(|(l, r)| (r - l).abs())
+            .sum()
+    }
+
+    pub fn puzzle2(input: &str) -> isize {
+        let (left, right) = parse(input);
+        let mut frequencies = HashMap::new();
+        for x in right {
+            *frequencies.entry(x).or_insert(0) += 1;
+        }
+        left.into_iter()
+            .map(|x| x * frequencies.get(&x).unwrap_or(&0))
+            .sum()
+    }
+}
+
+// A million lines of five-digit pairs from a fixed linear congruential generator.
+fn synthetic() -> String {
+    let mut state: u64 = 0x2024;
+    let mut next = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        10000 + (state >> 33) % 90000
+    };
+    let mut input = String::new();
+    for _ in 0..1_000_000 {
+        let (l, r) = (next(), next());
+        input += &format!("{l}   {r}\n");
+    }
+    input
+}
+
+#[bench]
+fn bench_puzzle1(b: &mut Bencher) {
+    let input = synthetic();
+    b.iter(|| day01::puzzle1(black_box(&input)));
+}
+
+#[bench]
+fn bench_puzzle1_baseline(b: &mut Bencher) {
+    let input = synthetic();
+    b.iter(|| baseline::puzzle1(black_box(&input)));
+}
+
+#[bench]
+fn bench_puzzle2(b: &mut Bencher) {
+    let input = synthetic();
+    assert_eq!(day01::puzzle2(&input), baseline::puzzle2(&input));
+    b.iter(|| day01::puzzle2(black_box(&input)));
+}
+
+#[bench]
+fn bench_puzzle2_baseline(b: &mut Bencher) {
+    let input = synthetic();
+    b.iter(|| baseline::puzzle2(black_box(&input)));
+}
@@ -1,38 +1,160 @@
-use std::collections::HashMap;
+use std::{cmp::Ordering, fmt};
 
-use itertools::Itertools;
+use crate::scan::Scan;
 
 fn parse(input: &str) -> (Vec<isize>, Vec<isize>) {
-    input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|x| x.parse::<isize>().unwrap())
-                .collect_tuple()
-                .unwrap()
-        })
-        .unzip()
+    // Lines are all formatted alike, so the first one's length gives the line count.
+    let width = input.find('\n').map_or(input.len(), |end| end + 1);
+    let lines = input.len() / width.max(1) + 1;
+    let mut left = Vec::with_capacity(lines);
+    let mut right = Vec::with_capacity(lines);
+    for line in input.lines() {
+        let [l, r] = line.ints().unwrap();
+        left.push(l);
+        right.push(r);
+    }
+    (left, right)
 }
 
-pub fn puzzle1(input: &str) -> isize {
+fn sorted(input: &str) -> (Vec<isize>, Vec<isize>) {
     let (mut left, mut right) = parse(input);
-    left.sort();
-    right.sort();
+    left.sort_unstable();
+    right.sort_unstable();
+    (left, right)
+}
+
+pub fn puzzle1(input: &str) -> isize {
+    let (left, right) = sorted(input);
     left.into_iter()
         .zip(right)
         .map(|(l, r)| (r - l).abs())
         .sum()
 }
 
+// Length of the run of `x` at the start of `list`.
+fn run(list: &[isize], x: isize) -> usize {
+    list.iter().take_while(|&&y| y == x).count()
+}
+
 pub fn puzzle2(input: &str) -> isize {
-    let (left, right) = parse(input);
-    let mut frequencies = HashMap::new();
-    for x in right {
-        *frequencies.entry(x).or_insert(0) += 1;
+    let (left, right) = sorted(input);
+    let (mut i, mut j) = (0, 0);
+    let mut similarity = 0;
+    while i < left.len() && j < right.len() {
+        let (l, r) = (left[i], right[j]);
+        match l.cmp(&r) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                let (a, b) = (run(&left[i..], l), run(&right[j..], r));
+                similarity += l * (a * b) as isize;
+                i += a;
+                j += b;
+            }
+        }
+    }
+    similarity
+}
+
+// The `i`th smallest values of each list, with what they contribute to both parts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Row {
+    pub left: isize,
+    pub right: isize,
+    pub distance: usize,
+    pub frequency: usize,
+    pub contribution: isize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub total_distance: usize,
+    pub similarity: isize,
+    pub max_distance: usize,
+    pub median_distance: f64,
+    // Bucket 0 counts distances of 0, bucket `k` those in `2^(k-1)..2^k`.
+    pub histogram: Vec<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Report {
+    pub rows: Vec<Row>,
+    pub stats: Stats,
+}
+
+fn bucket(distance: usize) -> usize {
+    (usize::BITS - distance.leading_zeros()) as usize
+}
+
+pub fn report(input: &str) -> Report {
+    let (left, right) = sorted(input);
+    let rows: Vec<_> = left
+        .iter()
+        .zip(&right)
+        .map(|(&l, &r)| {
+            let frequency = right.partition_point(|&x| x <= l) - right.partition_point(|&x| x < l);
+            Row {
+                left: l,
+                right: r,
+                distance: l.abs_diff(r),
+                frequency,
+                contribution: l * frequency as isize,
+            }
+        })
+        .collect();
+    let mut distances: Vec<_> = rows.iter().map(|row| row.distance).collect();
+    distances.sort_unstable();
+    let median_distance = match distances.len() {
+        0 => 0.0,
+        n if n % 2 == 1 => distances[n / 2] as f64,
+        n => (distances[n / 2 - 1] + distances[n / 2]) as f64 / 2.0,
+    };
+    let max_distance = distances.last().copied().unwrap_or(0);
+    let mut histogram = vec![0; bucket(max_distance) + 1];
+    for &distance in &distances {
+        histogram[bucket(distance)] += 1;
+    }
+    let stats = Stats {
+        total_distance: distances.iter().sum(),
+        similarity: rows.iter().map(|row| row.contribution).sum(),
+        max_distance,
+        median_distance,
+        histogram,
+    };
+    Report { rows, stats }
+}
+
+impl Report {
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("left,right,distance,frequency,contribution\n");
+        for row in &self.rows {
+            csv += &format!(
+                "{},{},{},{},{}\n",
+                row.left, row.right, row.distance, row.frequency, row.contribution,
+            );
+        }
+        csv
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let stats = &self.stats;
+        writeln!(f, "pairs: {}", self.rows.len())?;
+        writeln!(f, "total distance: {}", stats.total_distance)?;
+        writeln!(f, "similarity: {}", stats.similarity)?;
+        writeln!(f, "max distance: {}", stats.max_distance)?;
+        writeln!(f, "median distance: {}", stats.median_distance)?;
+        writeln!(f, "distance histogram:")?;
+        for (k, count) in stats.histogram.iter().enumerate() {
+            let range = match k {
+                0 => "0".to_string(),
+                _ => format!("{}-{}", 1usize << (k - 1), (1usize << k) - 1),
+            };
+            writeln!(f, "{range:>12}: {count}")?;
+        }
+        Ok(())
     }
-    left.into_iter()
-        .map(|x| x * frequencies.get(&x).unwrap_or(&0))
-        .sum()
 }
 
 #[cfg(test)]
@@ -61,4 +183,60 @@ mod tests {
     fn test_puzzle2_input() {
         assert_eq!(puzzle2(INPUT), 29379307);
     }
+
+    #[test]
+    fn test_puzzle2_repeats_on_both_sides() {
+        assert_eq!(puzzle2("3   3\n3   3\n1   3\n"), 18);
+        assert_eq!(puzzle2("5   1\n"), 0);
+    }
+
+    #[test]
+    fn test_report_example() {
+        let report = report(EXAMPLE);
+        assert_eq!(
+            report.rows[2],
+            Row {
+                left: 3,
+                right: 3,
+                distance: 0,
+                frequency: 3,
+                contribution: 9,
+            }
+        );
+        assert_eq!(
+            report.to_string(),
+            "\
+pairs: 6
+total distance: 11
+similarity: 31
+max distance: 5
+median distance: 1.5
+distance histogram:
+           0: 1
+         1-1: 2
+         2-3: 2
+         4-7: 1
+",
+        );
+        assert_eq!(
+            report.to_csv(),
+            "\
+left,right,distance,frequency,contribution
+1,3,2,0,0
+2,3,1,0,0
+3,3,0,3,9
+3,4,1,3,9
+3,5,2,3,9
+4,9,5,1,4
+",
+        );
+    }
+
+    #[test]
+    fn test_report_input() {
+        let stats = report(INPUT).stats;
+        assert_eq!(stats.total_distance, 3246517);
+        assert_eq!(stats.similarity, 29379307);
+        assert_eq!(stats.histogram.iter().sum::<usize>(), 1000);
+    }
 }
@@ -1,7 +1,9 @@
 use std::cmp::Ordering;
 
+use crate::scan::Scan;
+
 fn parse(line: &str) -> impl Iterator<Item = isize> + '_ {
-    line.split_whitespace().map(|level| level.parse().unwrap())
+    line.numbers().map(|level| level.unwrap().value)
 }
 
 fn safe(levels: impl IntoIterator<Item = isize>) -> bool {
@@ -1,26 +1,33 @@
 use std::{cmp::Ordering, collections::HashSet};
 
-use itertools::Itertools;
+use crate::{input::Input, scan::Scan};
 
-fn parse(input: &str) -> (HashSet<(usize, usize)>, &str) {
-    let (rules, updates) = input.split("\n\n").collect_tuple().unwrap();
-    (
-        rules
-            .lines()
-            .map(|line| {
-                line.split('|')
-                    .map(|x| x.parse().unwrap())
-                    .collect_tuple()
-                    .unwrap()
-            })
-            .collect(),
-        updates,
-    )
+type Rules = HashSet<(usize, usize)>;
+
+fn parse(input: &str) -> (Rules, Vec<Vec<usize>>) {
+    let input = Input::new(input);
+    let mut sections = input.sections();
+    let rules = sections
+        .parse("rules", |block| {
+            block
+                .lines()
+                .map(|line| {
+                    let [x, y] = line.ints().unwrap();
+                    (x, y)
+                })
+                .collect()
+        })
+        .unwrap();
+    let updates = sections
+        .parse("updates", |block| {
+            block.lines().map(|line| line.all_ints().unwrap()).collect()
+        })
+        .unwrap();
+    (rules, updates)
 }
 
-fn parse_update(rules: &HashSet<(usize, usize)>, update: &str) -> (Vec<usize>, Vec<usize>) {
-    let pages: Vec<usize> = update.split(',').map(|x| x.parse().unwrap()).collect();
-    let mut sorted = pages.clone();
+fn sort(rules: &Rules, pages: &[usize]) -> Vec<usize> {
+    let mut sorted = pages.to_vec();
     sorted.sort_by(
         |&x, &y| match (rules.contains(&(x, y)), rules.contains(&(y, x))) {
             (true, false) => Ordering::Less,
@@ -28,7 +35,7 @@ fn parse_update(rules: &HashSet<(usize, usize)>, update: &str) -> (Vec<usize>, V
             _ => panic!(),
         },
     );
-    (pages, sorted)
+    sorted
 }
 
 fn middle(pages: &[usize]) -> usize {
@@ -38,9 +45,9 @@ fn middle(pages: &[usize]) -> usize {
 pub fn puzzle1(input: &str) -> usize {
     let (rules, updates) = parse(input);
     updates
-        .lines()
-        .filter_map(|line| {
-            let (pages, sorted) = parse_update(&rules, line);
+        .into_iter()
+        .filter_map(|pages| {
+            let sorted = sort(&rules, &pages);
             if pages == sorted {
                 Some(middle(&pages))
             } else {
@@ -53,9 +60,9 @@ pub fn puzzle1(input: &str) -> usize {
 pub fn puzzle2(input: &str) -> usize {
     let (rules, updates) = parse(input);
     updates
-        .lines()
-        .filter_map(|line| {
-            let (pages, sorted) = parse_update(&rules, line);
+        .into_iter()
+        .filter_map(|pages| {
+            let sorted = sort(&rules, &pages);
             if pages == sorted {
                 None
             } else {
@@ -77,6 +84,11 @@ mod tests {
         assert_eq!(puzzle1(EXAMPLE), 143);
     }
 
+    #[test]
+    fn test_puzzle1_example_crlf() {
+        assert_eq!(puzzle1(&EXAMPLE.replace('\n', "\r\n")), 143);
+    }
+
     #[test]
     fn test_puzzle1_input() {
         assert_eq!(puzzle1(INPUT), 5166);
@@ -1,49 +1,12 @@
-use enumset::{EnumSet, EnumSetType};
+use enumset::EnumSetType;
 
-struct Grid<'a> {
-    string: &'a str,
-    width: usize,
-    start: (isize, isize),
-}
-
-impl<'a> Grid<'a> {
-    fn new(string: &'a str) -> Self {
-        let width = string.find('\n').unwrap();
-        let start = string.find('^').unwrap();
-        let x = start % (width + 1);
-        let y = start / (width + 1);
-        Self {
-            string,
-            width,
-            start: (x as isize, y as isize),
-        }
-    }
-
-    fn width(&self) -> usize {
-        self.width
-    }
-
-    fn height(&self) -> usize {
-        self.string.len() / (self.width() + 1)
-    }
-
-    fn start(&self) -> (isize, isize) {
-        self.start
-    }
-
-    fn get(&self, x: isize, y: isize) -> Option<&str> {
-        let i: usize = y.try_into().ok()?;
-        let j: usize = x.try_into().ok()?;
-        if j >= self.width() {
-            return None;
-        }
-        let k = i * (self.width() + 1) + j;
-        self.string.get(k..k + 1)
-    }
-}
+use crate::{
+    grid::{Grid, Point},
+    visit::DirectedVisits,
+};
 
-#[derive(EnumSetType)]
-enum Direction {
+#[derive(Debug, Hash, EnumSetType)]
+pub enum Direction {
     North,
     East,
     South,
@@ -51,7 +14,7 @@ enum Direction {
 }
 
 impl Direction {
-    fn vector(self) -> (isize, isize) {
+    pub fn vector(self) -> (isize, isize) {
         match self {
             Self::North => (0, -1),
             Self::East => (1, 0),
@@ -60,7 +23,7 @@ impl Direction {
         }
     }
 
-    fn rotate(self) -> Self {
+    pub fn rotate(self) -> Self {
         match self {
             Self::North => Self::East,
             Self::East => Self::South,
@@ -68,44 +31,36 @@ impl Direction {
             Self::West => Self::North,
         }
     }
-}
-
-struct Visits {
-    width: usize,
-    positions: Vec<EnumSet<Direction>>,
-}
-
-impl Visits {
-    fn new(grid: &Grid) -> Self {
-        let width = grid.width();
-        let positions = vec![EnumSet::empty(); width * grid.height()];
-        Self { width, positions }
-    }
-
-    fn visit(&mut self, x: isize, y: isize, direction: Direction) -> bool {
-        self.positions[y as usize * self.width + x as usize].insert(direction)
-    }
 
-    fn iter(&self) -> impl Iterator<Item = EnumSet<Direction>> + '_ {
-        self.positions.iter().copied()
+    pub fn counter_rotate(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
     }
 }
 
-fn patrol(grid: &Grid, obstacle: Option<(isize, isize)>) -> Option<usize> {
-    let mut visited = Visits::new(grid);
-    let (mut x, mut y) = grid.start();
+fn patrol(
+    grid: &Grid<u8>,
+    visited: &mut DirectedVisits<Direction>,
+    start: Point,
+    obstacle: Option<Point>,
+) -> bool {
+    let (mut x, mut y) = start;
     let mut direction = Direction::North;
     loop {
         match grid.get(x, y) {
-            None => break,
-            Some("^" | ".") => {
-                if !visited.visit(x, y, direction) {
-                    return None;
+            None => return true,
+            Some(b'^' | b'.') => {
+                if !visited.insert(x, y, direction) {
+                    return false;
                 }
                 let (dx, dy) = direction.vector();
                 x += dx;
                 y += dy;
-                if grid.get(x, y) == Some("#") || Some((x, y)) == obstacle {
+                if grid.get(x, y) == Some(&b'#') || Some((x, y)) == obstacle {
                     x -= dx;
                     y -= dy;
                     direction = direction.rotate();
@@ -114,29 +69,29 @@ fn patrol(grid: &Grid, obstacle: Option<(isize, isize)>) -> Option<usize> {
             Some(_) => panic!(),
         }
     }
-    Some(
-        visited
-            .iter()
-            .filter(|directions| !directions.is_empty())
-            .count(),
-    )
+}
+
+fn parse(input: &str) -> (Grid<u8>, Point, DirectedVisits<Direction>) {
+    let grid = Grid::parse(input);
+    let start = grid.position(|&c| c == b'^').unwrap();
+    let visited = DirectedVisits::new(grid.width(), grid.height());
+    (grid, start, visited)
 }
 
 pub fn puzzle1(input: &str) -> usize {
-    patrol(&Grid::new(input), None).unwrap()
+    let (grid, start, mut visited) = parse(input);
+    assert!(patrol(&grid, &mut visited, start, None));
+    visited.count()
 }
 
 pub fn puzzle2(input: &str) -> usize {
-    let mut positions = 0;
-    let grid = Grid::new(input);
-    for y in 0..(grid.height() as isize) {
-        for x in 0..(grid.width() as isize) {
-            if (x, y) != grid.start() && patrol(&grid, Some((x, y))).is_none() {
-                positions += 1;
-            }
-        }
-    }
-    positions
+    let (grid, start, mut visited) = parse(input);
+    grid.points()
+        .filter(|&p| {
+            visited.clear();
+            p != start && !patrol(&grid, &mut visited, start, Some(p))
+        })
+        .count()
 }
 
 #[cfg(test)]
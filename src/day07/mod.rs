@@ -1,4 +1,4 @@
-use itertools::Itertools;
+use crate::scan::Scan;
 
 type Ops<const N: usize> = [fn(usize, usize) -> Option<usize>; N];
 
@@ -18,10 +18,9 @@ fn solve<const N: usize>(ops: Ops<N>, input: &str) -> usize {
     input
         .lines()
         .filter_map(|line| {
-            let (first, rest) = line.split(": ").collect_tuple().unwrap();
-            let test: usize = first.parse().unwrap();
-            let numbers: Vec<usize> = rest.split(' ').map(|x| x.parse().unwrap()).collect();
-            if sat(ops, test, &numbers) {
+            let ints = line.all_ints().unwrap();
+            let (&test, numbers) = ints.split_first().unwrap();
+            if sat(ops, test, numbers) {
                 Some(test)
             } else {
                 None
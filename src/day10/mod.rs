@@ -1,102 +1,52 @@
-struct Grid<'a> {
-    string: &'a str,
-    width: usize,
-}
-
-impl<'a> Grid<'a> {
-    fn new(string: &'a str) -> Self {
-        let width = string.find('\n').unwrap();
-        Self { string, width }
-    }
-
-    fn width(&self) -> usize {
-        self.width
-    }
-
-    fn height(&self) -> usize {
-        self.string.len() / (self.width() + 1)
-    }
-
-    fn table<T: Clone>(&self, fill: T) -> Table<T> {
-        Table::new(self.width(), self.height(), fill)
-    }
-
-    fn index(&self, i: usize) -> (isize, isize) {
-        let x = (i % (self.width() + 1)) as isize;
-        let y = (i / (self.width() + 1)) as isize;
-        (x, y)
-    }
+use crate::{
+    grid::{Grid, Point},
+    visit::Visited,
+};
 
-    fn get(&self, x: isize, y: isize) -> Option<&str> {
-        let i: usize = y.try_into().ok()?;
-        let j: usize = x.try_into().ok()?;
-        if j >= self.width() {
-            return None;
+fn hike(grid: &Grid<u8>, start: Point, mut visited: Option<&mut Visited>) -> usize {
+    let mut ends = 0;
+    let mut stack = vec![(b'0', start)];
+    while let Some((h, (x, y))) = stack.pop() {
+        if grid.get(x, y) != Some(&h) {
+            continue;
         }
-        let k = i * (self.width() + 1) + j;
-        self.string.get(k..k + 1)
-    }
-}
-
-struct Table<T> {
-    width: usize,
-    elems: Vec<T>,
-}
-
-impl<T: Clone> Table<T> {
-    fn new(width: usize, height: usize, fill: T) -> Self {
-        let elems = vec![fill; width * height];
-        Self { width, elems }
+        if let Some(visited) = &mut visited {
+            if !visited.insert(x, y) {
+                continue;
+            }
+        }
+        if h == b'9' {
+            ends += 1;
+            continue;
+        }
+        stack.extend(grid.neighbors4(x, y).map(|p| (h + 1, p)));
     }
+    ends
 }
 
-impl<T> Table<T> {
-    fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
-        let i: usize = y.try_into().ok()?;
-        let j: usize = x.try_into().ok()?;
-        if j >= self.width {
-            return None;
-        }
-        self.elems.get_mut(i * self.width + j)
-    }
+fn trailheads(grid: &Grid<u8>) -> impl Iterator<Item = Point> + '_ {
+    grid.points()
+        .filter(|&(x, y)| grid.get(x, y) == Some(&b'0'))
 }
 
 pub fn puzzle1(input: &str) -> usize {
-    let grid = Grid::new(input);
-    input
-        .chars()
-        .enumerate()
-        .filter(|&(_, c)| c == '0')
-        .map(|(i, _)| {
-            let mut visited = grid.table(false);
-            let mut stack = vec![(0, grid.index(i))];
-            while let Some((n, (x, y))) = stack.pop() {
-                if grid.get(x, y).and_then(|s| s.parse().ok()) != Some(n) {
-                    continue;
-                }
-                let here = visited.get_mut(x, y).unwrap();
-                if *here {
-                    continue;
-                }
-                *here = true;
-                stack.push((n + 1, (x + 1, y)));
-                stack.push((n + 1, (x, y - 1)));
-                stack.push((n + 1, (x - 1, y)));
-                stack.push((n + 1, (x, y + 1)));
-            }
-            let mut score = 0;
-            for y in 0..(grid.height() as isize) {
-                for x in 0..(grid.width() as isize) {
-                    if grid.get(x, y) == Some("9") && *visited.get_mut(x, y).unwrap() {
-                        score += 1;
-                    }
-                }
-            }
-            score
+    let grid = Grid::parse(input);
+    let mut visited = Visited::new(grid.width(), grid.height());
+    trailheads(&grid)
+        .map(|start| {
+            visited.clear();
+            hike(&grid, start, Some(&mut visited))
         })
         .sum()
 }
 
+pub fn puzzle2(input: &str) -> usize {
+    let grid = Grid::parse(input);
+    trailheads(&grid)
+        .map(|start| hike(&grid, start, None))
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,4 +63,14 @@ mod tests {
     fn test_puzzle1_input() {
         assert_eq!(puzzle1(INPUT), 667);
     }
+
+    #[test]
+    fn test_puzzle2_example() {
+        assert_eq!(puzzle2(EXAMPLE), 81);
+    }
+
+    #[test]
+    fn test_puzzle2_input() {
+        assert_eq!(puzzle2(INPUT), 1344);
+    }
 }
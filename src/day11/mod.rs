@@ -1,12 +1,13 @@
 use std::collections::HashMap;
 
+use crate::scan::Scan;
+
 type Stones = HashMap<usize, usize>;
 
 fn parse(input: &str) -> Stones {
     let mut stones = Stones::new();
-    for stone in input.split_whitespace() {
-        let x = stone.parse().unwrap();
-        *stones.entry(x).or_default() += 1;
+    for stone in input.numbers() {
+        *stones.entry(stone.unwrap().value).or_default() += 1;
     }
     stones
 }
@@ -0,0 +1,60 @@
+use crate::{
+    grid::Grid,
+    region::{label, Connectivity, Region},
+};
+
+fn solve(input: &str, price: impl Fn(&Region) -> usize) -> usize {
+    let grid = Grid::parse(input);
+    label(&grid, Connectivity::Four)
+        .regions
+        .iter()
+        .map(|region| region.area() * price(region))
+        .sum()
+}
+
+pub fn puzzle1(input: &str) -> usize {
+    solve(input, |region| region.perimeter)
+}
+
+pub fn puzzle2(input: &str) -> usize {
+    solve(input, Region::sides)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example1.txt");
+    const EXAMPLE2: &str = include_str!("example2.txt");
+    const EXAMPLE3: &str = include_str!("example3.txt");
+
+    #[test]
+    fn test_puzzle1_example1() {
+        assert_eq!(puzzle1(EXAMPLE1), 140);
+    }
+
+    #[test]
+    fn test_puzzle1_example2() {
+        assert_eq!(puzzle1(EXAMPLE2), 772);
+    }
+
+    #[test]
+    fn test_puzzle1_example3() {
+        assert_eq!(puzzle1(EXAMPLE3), 1930);
+    }
+
+    #[test]
+    fn test_puzzle2_example1() {
+        assert_eq!(puzzle2(EXAMPLE1), 80);
+    }
+
+    #[test]
+    fn test_puzzle2_example2() {
+        assert_eq!(puzzle2(EXAMPLE2), 436);
+    }
+
+    #[test]
+    fn test_puzzle2_example3() {
+        assert_eq!(puzzle2(EXAMPLE3), 1206);
+    }
+}
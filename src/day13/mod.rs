@@ -0,0 +1,172 @@
+use std::cmp::Ordering;
+
+use crate::{input::Input, scan::Scan};
+
+type Vector = (i128, i128);
+
+struct Machine {
+    a: Vector,
+    b: Vector,
+    prize: Vector,
+}
+
+fn parse(input: &str) -> Vec<Machine> {
+    Input::new(input)
+        .sections()
+        .map(|block| {
+            let [ax, ay, bx, by, px, py]: [i64; 6] = block.ints().unwrap();
+            Machine {
+                a: (ax.into(), ay.into()),
+                b: (bx.into(), by.into()),
+                prize: (px.into(), py.into()),
+            }
+        })
+        .collect()
+}
+
+fn cross(u: Vector, v: Vector) -> Option<i128> {
+    u.0.checked_mul(v.1)?.checked_sub(u.1.checked_mul(v.0)?)
+}
+
+fn cost(a: i128, b: i128) -> Option<i128> {
+    a.checked_mul(3)?.checked_add(b)
+}
+
+fn gcd(x: i128, y: i128) -> i128 {
+    if y == 0 {
+        x.abs()
+    } else {
+        gcd(y, x % y)
+    }
+}
+
+fn ext_gcd(x: i128, y: i128) -> (i128, i128, i128) {
+    if y == 0 {
+        (x.abs(), x.signum(), 0)
+    } else {
+        let (g, s, t) = ext_gcd(y, x % y);
+        (g, t, s - (x / y) * t)
+    }
+}
+
+// Bounds on `k` such that `k * c + v >= 0`.
+fn bound(lo: &mut Option<i128>, hi: &mut Option<i128>, c: i128, v: i128) {
+    match c.cmp(&0) {
+        Ordering::Greater => {
+            let k = -v.div_euclid(c);
+            *lo = Some(lo.map_or(k, |lo| lo.max(k)));
+        }
+        Ordering::Less => {
+            let k = v.div_euclid(-c);
+            *hi = Some(hi.map_or(k, |hi| hi.min(k)));
+        }
+        Ordering::Equal => {}
+    }
+}
+
+// Cheapest nonnegative `a * alpha + b * beta == pi`.
+fn line(alpha: i128, beta: i128, pi: i128) -> Option<i128> {
+    match (alpha, beta) {
+        (0, 0) => (pi == 0).then_some(0),
+        (0, _) => (pi % beta == 0 && pi / beta >= 0).then(|| pi / beta),
+        (_, 0) => (pi % alpha == 0 && pi / alpha >= 0).then(|| 3 * (pi / alpha)),
+        _ => {
+            let (g, x, y) = ext_gcd(alpha, beta);
+            if pi % g != 0 {
+                return None;
+            }
+            let a0 = x.checked_mul(pi / g)?;
+            let b0 = y.checked_mul(pi / g)?;
+            let (s, t) = (beta / g, alpha / g);
+            let (mut lo, mut hi) = (None, None);
+            bound(&mut lo, &mut hi, s, a0);
+            bound(&mut lo, &mut hi, -t, b0);
+            if let (Some(lo), Some(hi)) = (lo, hi) {
+                if lo > hi {
+                    return None;
+                }
+            }
+            let k = match (3 * s - t).cmp(&0) {
+                Ordering::Greater => lo?,
+                Ordering::Less => hi?,
+                Ordering::Equal => lo.or(hi)?,
+            };
+            cost(
+                a0.checked_add(k.checked_mul(s)?)?,
+                b0.checked_sub(k.checked_mul(t)?)?,
+            )
+        }
+    }
+}
+
+fn tokens(&Machine { a, b, prize }: &Machine) -> Option<i128> {
+    let det = cross(a, b)?;
+    if det != 0 {
+        let i = cross(prize, b)?;
+        let j = cross(a, prize)?;
+        if i % det != 0 || j % det != 0 || i / det < 0 || j / det < 0 {
+            return None;
+        }
+        return cost(i / det, j / det);
+    }
+    let Some(d) = [a, b].into_iter().find(|&v| v != (0, 0)) else {
+        return (prize == (0, 0)).then_some(0);
+    };
+    if cross(d, prize)? != 0 {
+        return None;
+    }
+    let g = gcd(d.0, d.1);
+    let d = (d.0 / g, d.1 / g);
+    let scale = |v: Vector| if d.0 != 0 { v.0 / d.0 } else { v.1 / d.1 };
+    line(scale(a), scale(b), scale(prize))
+}
+
+fn solve(input: &str, offset: i128) -> usize {
+    parse(input)
+        .into_iter()
+        .filter_map(|mut machine| {
+            machine.prize.0 += offset;
+            machine.prize.1 += offset;
+            tokens(&machine)
+        })
+        .sum::<i128>()
+        .try_into()
+        .unwrap()
+}
+
+pub fn puzzle1(input: &str) -> usize {
+    solve(input, 0)
+}
+
+pub fn puzzle2(input: &str) -> usize {
+    solve(input, 10000000000000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_puzzle1_example() {
+        assert_eq!(puzzle1(EXAMPLE), 480);
+    }
+
+    #[test]
+    fn test_puzzle2_example() {
+        assert_eq!(puzzle2(EXAMPLE), 875318608908);
+    }
+
+    #[test]
+    fn test_collinear_buttons() {
+        let machine = |a, b, prize| tokens(&Machine { a, b, prize });
+        assert_eq!(machine((2, 4), (1, 2), (7, 14)), Some(7));
+        assert_eq!(machine((4, 4), (1, 1), (7, 7)), Some(6));
+        assert_eq!(machine((6, 3), (4, 2), (10, 5)), Some(4));
+        assert_eq!(machine((6, 3), (4, 2), (10, 6)), None);
+        assert_eq!(machine((6, 3), (4, 2), (5, 5)), None);
+        assert_eq!(machine((0, 0), (3, 0), (9, 0)), Some(3));
+        assert_eq!(machine((0, 0), (0, 0), (0, 0)), Some(0));
+    }
+}
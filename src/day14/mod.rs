@@ -0,0 +1,164 @@
+use crate::{
+    grid::{Grid, Point},
+    scan::Scan,
+};
+
+const WIDTH: usize = 101;
+const HEIGHT: usize = 103;
+
+struct Robot {
+    position: Point,
+    velocity: Point,
+}
+
+fn parse(input: &str) -> Vec<Robot> {
+    input
+        .lines()
+        .map(|line| {
+            let [px, py, vx, vy] = line.ints().unwrap();
+            Robot {
+                position: (px, py),
+                velocity: (vx, vy),
+            }
+        })
+        .collect()
+}
+
+fn simulate(area: &Grid<usize>, robots: &[Robot], seconds: isize) -> Grid<usize> {
+    let mut counts = area.clone();
+    for robot in robots {
+        let (x, y) = robot.position;
+        let (dx, dy) = robot.velocity;
+        *counts.get_mut(x + seconds * dx, y + seconds * dy).unwrap() += 1;
+    }
+    counts
+}
+
+pub fn safety_factor(input: &str, width: usize, height: usize, seconds: isize) -> usize {
+    let area = Grid::new(width, height, 0).wrapping();
+    let counts = simulate(&area, &parse(input), seconds);
+    let (mx, my) = ((width / 2) as isize, (height / 2) as isize);
+    let mut quadrants = [0; 4];
+    for (x, y) in counts.points() {
+        if x != mx && y != my {
+            quadrants[usize::from(x > mx) + 2 * usize::from(y > my)] += counts.get(x, y).unwrap();
+        }
+    }
+    quadrants.into_iter().product()
+}
+
+pub fn puzzle1(input: &str) -> usize {
+    safety_factor(input, WIDTH, HEIGHT, 100)
+}
+
+// Scaled variance of the robots along one axis after `t` seconds.
+fn spread(coords: &[(isize, isize)], modulus: usize, t: isize) -> isize {
+    let n = coords.len() as isize;
+    let (mut sum, mut squares) = (0, 0);
+    for &(p, v) in coords {
+        let q = (p + t * v).rem_euclid(modulus as isize);
+        sum += q;
+        squares += q * q;
+    }
+    n * squares - sum * sum
+}
+
+fn tightest(coords: &[(isize, isize)], modulus: usize) -> usize {
+    (0..modulus)
+        .min_by_key(|&t| spread(coords, modulus, t as isize))
+        .unwrap()
+}
+
+pub fn easter_egg(input: &str, width: usize, height: usize) -> usize {
+    let robots = parse(input);
+    let xs: Vec<_> = robots
+        .iter()
+        .map(|r| (r.position.0, r.velocity.0))
+        .collect();
+    let ys: Vec<_> = robots
+        .iter()
+        .map(|r| (r.position.1, r.velocity.1))
+        .collect();
+    let tx = tightest(&xs, width);
+    let ty = tightest(&ys, height);
+    (tx..width * height)
+        .step_by(width)
+        .find(|t| t % height == ty)
+        .unwrap()
+}
+
+pub fn render(input: &str, width: usize, height: usize, seconds: isize) -> String {
+    let area = Grid::new(width, height, 0).wrapping();
+    let counts = simulate(&area, &parse(input), seconds);
+    let mut picture = String::new();
+    for y in 0..height as isize {
+        for x in 0..width as isize {
+            picture.push(match counts.get(x, y).unwrap() {
+                0 => '.',
+                &n => char::from_digit(n.min(9) as u32, 10).unwrap(),
+            });
+        }
+        picture.push('\n');
+    }
+    picture
+}
+
+pub fn puzzle2(input: &str) -> usize {
+    easter_egg(input, WIDTH, HEIGHT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_puzzle1_example() {
+        assert_eq!(safety_factor(EXAMPLE, 11, 7, 100), 12);
+    }
+
+    #[test]
+    fn test_render_example() {
+        assert_eq!(
+            render(EXAMPLE, 11, 7, 0),
+            "\
+1.12.......
+...........
+...........
+......11.11
+1.1........
+.........1.
+.......1...
+",
+        );
+        assert_eq!(
+            render(EXAMPLE, 11, 7, 100),
+            "\
+......2..1.
+...........
+1..........
+.11........
+.....1.....
+...12......
+.1....1....
+",
+        );
+    }
+
+    #[test]
+    fn test_easter_egg_cluster() {
+        let (width, height, seconds): (isize, isize, isize) = (11, 7, 30);
+        let lines: Vec<String> = (0..20)
+            .map(|i| {
+                let (vx, vy) = (1 + i % 9, -1 - i % 5);
+                let (x, y) = (4 + i % 3, 2 + i % 2);
+                let px = (x - seconds * vx).rem_euclid(width);
+                let py = (y - seconds * vy).rem_euclid(height);
+                format!("p={px},{py} v={vx},{vy}")
+            })
+            .collect();
+        let input = lines.join("\n");
+        assert_eq!(easter_egg(&input, 11, 7), 30);
+    }
+}
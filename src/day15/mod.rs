@@ -0,0 +1,178 @@
+use std::{fmt, mem};
+
+use crate::{
+    grid::{Grid, Point},
+    input::Input,
+    visit::Visited,
+};
+
+pub struct Warehouse {
+    grid: Grid<u8>,
+    robot: Point,
+    pushed: Visited,
+}
+
+impl Warehouse {
+    fn new(map: &str, wide: bool) -> Self {
+        let grid = if wide {
+            let widened: String = map
+                .chars()
+                .map(|c| match c {
+                    '#' => "##",
+                    'O' => "[]",
+                    '.' => "..",
+                    '@' => "@.",
+                    '\n' => "\n",
+                    _ => panic!("unexpected map tile {c:?}"),
+                })
+                .collect();
+            Grid::parse(&widened)
+        } else {
+            Grid::parse(map)
+        };
+        let robot = grid.position(|&c| c == b'@').unwrap();
+        let pushed = Visited::new(grid.width(), grid.height());
+        Self {
+            grid,
+            robot,
+            pushed,
+        }
+    }
+
+    fn step(&mut self, (dx, dy): Point) -> bool {
+        self.pushed.clear();
+        let mut moving = vec![self.robot];
+        let mut i = 0;
+        while let Some(&(x, y)) = moving.get(i) {
+            i += 1;
+            let (x, y) = (x + dx, y + dy);
+            let also = match self.grid.get(x, y) {
+                Some(b'.') => continue,
+                Some(b'O') => None,
+                Some(b'[') => Some((x + 1, y)),
+                Some(b']') => Some((x - 1, y)),
+                _ => return false,
+            };
+            for (x, y) in [Some((x, y)), also].into_iter().flatten() {
+                if self.pushed.insert(x, y) {
+                    moving.push((x, y));
+                }
+            }
+        }
+        let tiles: Vec<u8> = moving
+            .iter()
+            .map(|&(x, y)| {
+                let tile = self.grid.get_mut(x, y).unwrap();
+                mem::replace(tile, b'.')
+            })
+            .collect();
+        for (&(x, y), tile) in moving.iter().zip(tiles) {
+            *self.grid.get_mut(x + dx, y + dy).unwrap() = tile;
+        }
+        self.robot = (self.robot.0 + dx, self.robot.1 + dy);
+        true
+    }
+
+    pub fn gps(&self) -> usize {
+        self.grid
+            .points()
+            .filter(|&(x, y)| matches!(self.grid.get(x, y), Some(b'O' | b'[')))
+            .map(|(x, y)| 100 * y as usize + x as usize)
+            .sum()
+    }
+}
+
+impl fmt::Display for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+fn direction(c: char) -> Option<Point> {
+    match c {
+        '^' => Some((0, -1)),
+        '>' => Some((1, 0)),
+        'v' => Some((0, 1)),
+        '<' => Some((-1, 0)),
+        _ => None,
+    }
+}
+
+pub fn replay(input: &str, wide: bool, mut trace: impl FnMut(char, &Warehouse)) -> Warehouse {
+    let input = Input::new(input);
+    let mut sections = input.sections();
+    let mut warehouse = sections
+        .parse("map", |map| Warehouse::new(map, wide))
+        .unwrap();
+    let moves = sections.section("moves").unwrap();
+    for c in moves.chars().filter(|c| !c.is_whitespace()) {
+        warehouse.step(direction(c).unwrap());
+        trace(c, &warehouse);
+    }
+    warehouse
+}
+
+pub fn puzzle1(input: &str) -> usize {
+    replay(input, false, |_, _| {}).gps()
+}
+
+pub fn puzzle2(input: &str) -> usize {
+    replay(input, true, |_, _| {}).gps()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example1.txt");
+    const EXAMPLE2: &str = include_str!("example2.txt");
+
+    #[test]
+    fn test_puzzle1_example1() {
+        assert_eq!(puzzle1(EXAMPLE1), 2028);
+    }
+
+    #[test]
+    fn test_puzzle1_example2() {
+        assert_eq!(puzzle1(EXAMPLE2), 10092);
+    }
+
+    #[test]
+    fn test_puzzle2_example2() {
+        assert_eq!(puzzle2(EXAMPLE2), 9021);
+    }
+
+    #[test]
+    fn test_replay_example1() {
+        let mut frames = vec![];
+        let warehouse = replay(EXAMPLE1, false, |c, w| frames.push(format!("{c}\n{w}")));
+        assert_eq!(frames.len(), 15);
+        assert_eq!(
+            frames[0],
+            "\
+<
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+",
+        );
+        assert_eq!(
+            warehouse.to_string(),
+            "\
+########
+#....OO#
+##.....#
+#.....O#
+#.#O@..#
+#...O..#
+#...O..#
+########
+",
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+use enumset::EnumSet;
+
+use crate::{
+    day06::Direction,
+    grid::{Grid, Point},
+    search::{dijkstra, Search},
+};
+
+type State = (Point, Direction);
+
+fn moves(grid: &Grid<u8>, ((x, y), direction): State) -> impl Iterator<Item = (State, usize)> {
+    let (dx, dy) = direction.vector();
+    let ahead = (x + dx, y + dy);
+    let forward = (grid.get(ahead.0, ahead.1) != Some(&b'#')).then_some(((ahead, direction), 1));
+    [
+        forward,
+        Some((((x, y), direction.rotate()), 1000)),
+        Some((((x, y), direction.counter_rotate()), 1000)),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+fn solve(input: &str) -> (Search<State>, Vec<State>) {
+    let grid = Grid::parse(input);
+    let start = grid.position(|&c| c == b'S').unwrap();
+    let end = grid.position(|&c| c == b'E').unwrap();
+    let search = dijkstra([(start, Direction::East)], |state| moves(&grid, state));
+    let best = EnumSet::<Direction>::all()
+        .iter()
+        .filter_map(|direction| search.dist((end, direction)))
+        .min()
+        .unwrap();
+    let ends = EnumSet::<Direction>::all()
+        .iter()
+        .map(|direction| (end, direction))
+        .filter(|&state| search.dist(state) == Some(best))
+        .collect();
+    (search, ends)
+}
+
+pub fn puzzle1(input: &str) -> usize {
+    let (search, ends) = solve(input);
+    search.dist(ends[0]).unwrap()
+}
+
+pub fn puzzle2(input: &str) -> usize {
+    let (search, ends) = solve(input);
+    let tiles: HashSet<Point> = search
+        .path_nodes(ends)
+        .into_iter()
+        .map(|(point, _)| point)
+        .collect();
+    tiles.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example1.txt");
+    const EXAMPLE2: &str = include_str!("example2.txt");
+
+    #[test]
+    fn test_puzzle1_example1() {
+        assert_eq!(puzzle1(EXAMPLE1), 7036);
+    }
+
+    #[test]
+    fn test_puzzle1_example2() {
+        assert_eq!(puzzle1(EXAMPLE2), 11048);
+    }
+
+    #[test]
+    fn test_puzzle2_example1() {
+        assert_eq!(puzzle2(EXAMPLE1), 45);
+    }
+
+    #[test]
+    fn test_puzzle2_example2() {
+        assert_eq!(puzzle2(EXAMPLE2), 64);
+    }
+}
@@ -0,0 +1,155 @@
+use itertools::Itertools;
+
+use crate::{input::Input, scan::Scan};
+
+type Registers = [u64; 3];
+
+fn parse(input: &str) -> (Registers, Vec<u8>) {
+    let input = Input::new(input);
+    let mut sections = input.sections();
+    let registers = sections
+        .parse("registers", |block| block.ints().unwrap())
+        .unwrap();
+    let program = sections
+        .parse("program", |block| block.all_ints().unwrap())
+        .unwrap();
+    (registers, program)
+}
+
+fn combo(registers: &Registers, operand: u8) -> u64 {
+    match operand {
+        0..=3 => operand.into(),
+        4..=6 => registers[usize::from(operand - 4)],
+        _ => panic!("reserved combo operand {operand}"),
+    }
+}
+
+fn shift(a: u64, amount: u64) -> u64 {
+    u32::try_from(amount)
+        .ok()
+        .and_then(|amount| a.checked_shr(amount))
+        .unwrap_or(0)
+}
+
+fn run(program: &[u8], mut registers: Registers) -> Vec<u8> {
+    const A: usize = 0;
+    const B: usize = 1;
+    const C: usize = 2;
+    let mut output = vec![];
+    let mut ip = 0;
+    while let Some(&[opcode, operand]) = program.get(ip..ip + 2) {
+        ip += 2;
+        let literal = u64::from(operand);
+        match opcode {
+            0 => registers[A] = shift(registers[A], combo(&registers, operand)),
+            1 => registers[B] ^= literal,
+            2 => registers[B] = combo(&registers, operand) % 8,
+            3 => {
+                if registers[A] != 0 {
+                    ip = operand.into();
+                }
+            }
+            4 => registers[B] ^= registers[C],
+            5 => output.push((combo(&registers, operand) % 8) as u8),
+            6 => registers[B] = shift(registers[A], combo(&registers, operand)),
+            7 => registers[C] = shift(registers[A], combo(&registers, operand)),
+            _ => panic!("invalid opcode {opcode}"),
+        }
+    }
+    output
+}
+
+pub fn puzzle1(input: &str) -> String {
+    let (registers, program) = parse(input);
+    run(&program, registers).into_iter().join(",")
+}
+
+// Assumes the program shifts A right by three bits per output, as puzzle inputs do.
+fn quine(program: &[u8], registers: Registers, i: usize, a: u64) -> Option<u64> {
+    if i == 0 {
+        return Some(a);
+    }
+    (0..8).find_map(|d| {
+        let a = a.checked_mul(8)? + d;
+        let [_, b, c] = registers;
+        if run(program, [a, b, c]) == program[i - 1..] {
+            quine(program, registers, i - 1, a)
+        } else {
+            None
+        }
+    })
+}
+
+pub fn puzzle2(input: &str) -> u64 {
+    let (registers, program) = parse(input);
+    quine(&program, registers, program.len(), 0).unwrap()
+}
+
+fn describe(operand: u8) -> String {
+    match operand {
+        0..=3 => operand.to_string(),
+        4 => "a".to_string(),
+        5 => "b".to_string(),
+        6 => "c".to_string(),
+        _ => "?".to_string(),
+    }
+}
+
+pub fn disassemble(input: &str) -> String {
+    let (_, program) = parse(input);
+    let mut lines = String::new();
+    for (ip, [opcode, operand]) in program.into_iter().array_chunks::<2>().enumerate() {
+        let combo = describe(operand);
+        let instruction = match opcode {
+            0 => format!("a = a >> {combo}"),
+            1 => format!("b = b ^ {operand}"),
+            2 => format!("b = {combo} % 8"),
+            3 => format!("if a != 0 goto {operand}"),
+            4 => "b = b ^ c".to_string(),
+            5 => format!("out {combo} % 8"),
+            6 => format!("b = a >> {combo}"),
+            7 => format!("c = a >> {combo}"),
+            _ => format!("invalid {opcode} {operand}"),
+        };
+        lines += &format!("{:>2}: {instruction}\n", 2 * ip);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example1.txt");
+    const EXAMPLE2: &str = include_str!("example2.txt");
+
+    #[test]
+    fn test_puzzle1_example1() {
+        assert_eq!(puzzle1(EXAMPLE1), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn test_puzzle2_example2() {
+        assert_eq!(puzzle2(EXAMPLE2), 117440);
+    }
+
+    #[test]
+    fn test_small_programs() {
+        assert_eq!(run(&[5, 0, 5, 1, 5, 4], [10, 0, 0]), [0, 1, 2]);
+        assert_eq!(
+            run(&[0, 1, 5, 4, 3, 0], [2024, 0, 0]),
+            [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]
+        );
+        assert_eq!(run(&[2, 6, 5, 5], [0, 0, 9]), [1]);
+        assert_eq!(run(&[1, 7, 5, 5], [0, 29, 0]), [2]);
+        assert_eq!(run(&[4, 0, 5, 5], [0, 2024, 43690]), [2]);
+    }
+
+    #[test]
+    fn test_disassemble() {
+        assert_eq!(
+            disassemble(EXAMPLE1),
+            " 0: a = a >> 1\n 2: out a % 8\n 4: if a != 0 goto 0\n",
+        );
+    }
+}
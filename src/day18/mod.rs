@@ -0,0 +1,90 @@
+use crate::{
+    grid::{Grid, Point},
+    scan::Scan,
+    search::astar,
+};
+
+fn parse(input: &str) -> Vec<Point> {
+    input
+        .lines()
+        .map(|line| {
+            let [x, y] = line.ints().unwrap();
+            (x, y)
+        })
+        .collect()
+}
+
+fn escape(size: usize, bytes: &[Point]) -> Option<usize> {
+    let mut corrupted = Grid::new(size, size, false);
+    for &(x, y) in bytes {
+        *corrupted.get_mut(x, y).unwrap() = true;
+    }
+    if corrupted.get(0, 0) == Some(&true) {
+        return None;
+    }
+    let exit = (size as isize - 1, size as isize - 1);
+    let (steps, _) = astar(
+        (0, 0),
+        |p| p == exit,
+        |(x, y)| {
+            corrupted
+                .neighbors4(x, y)
+                .filter(|&(x, y)| corrupted.get(x, y) == Some(&false))
+                .map(|p| (p, 1))
+        },
+        |(x, y)| x.abs_diff(exit.0) + y.abs_diff(exit.1),
+    )?;
+    Some(steps)
+}
+
+pub fn shortest(input: &str, size: usize, count: usize) -> usize {
+    escape(size, &parse(input)[..count]).unwrap()
+}
+
+pub fn first_blocker(input: &str, size: usize) -> Point {
+    let bytes = parse(input);
+    assert!(escape(size, &bytes).is_none());
+    let (mut lo, mut hi) = (0, bytes.len());
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if escape(size, &bytes[..=mid]).is_some() {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    bytes[lo]
+}
+
+pub fn puzzle1(input: &str) -> usize {
+    shortest(input, 71, 1024)
+}
+
+pub fn puzzle2(input: &str) -> String {
+    let (x, y) = first_blocker(input, 71);
+    format!("{x},{y}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_puzzle1_example() {
+        assert_eq!(shortest(EXAMPLE, 7, 12), 22);
+    }
+
+    #[test]
+    fn test_puzzle2_example() {
+        assert_eq!(first_blocker(EXAMPLE, 7), (6, 1));
+    }
+
+    #[test]
+    fn test_byte_on_start() {
+        assert_eq!(escape(3, &[(0, 0)]), None);
+        assert_eq!(escape(3, &[(1, 1)]), Some(4));
+        assert_eq!(first_blocker("1,1\n0,0\n2,0\n", 3), (0, 0));
+    }
+}
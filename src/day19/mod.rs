@@ -0,0 +1,122 @@
+use crate::input::Input;
+
+#[derive(Default)]
+struct Node {
+    children: Vec<(u8, usize)>,
+    terminal: bool,
+}
+
+struct Trie {
+    nodes: Vec<Node>,
+}
+
+impl Trie {
+    fn new<'a>(words: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut trie = Self {
+            nodes: vec![Node::default()],
+        };
+        for word in words {
+            let mut i = 0;
+            for c in word.bytes() {
+                i = match trie.child(i, c) {
+                    Some(j) => j,
+                    None => {
+                        let j = trie.nodes.len();
+                        trie.nodes.push(Node::default());
+                        trie.nodes[i].children.push((c, j));
+                        j
+                    }
+                };
+            }
+            trie.nodes[i].terminal = true;
+        }
+        trie
+    }
+
+    fn child(&self, i: usize, c: u8) -> Option<usize> {
+        let (_, j) = self.nodes[i].children.iter().find(|&&(d, _)| d == c)?;
+        Some(*j)
+    }
+
+    fn prefixes<'a>(&'a self, s: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+        s.iter()
+            .scan(0, |i, &c| {
+                *i = self.child(*i, c)?;
+                Some(*i)
+            })
+            .enumerate()
+            .filter(|&(_, i)| self.nodes[i].terminal)
+            .map(|(k, _)| k + 1)
+    }
+}
+
+fn parse(input: &str) -> (Trie, Vec<String>) {
+    let input = Input::new(input);
+    let mut sections = input.sections();
+    let trie = sections
+        .parse("patterns", |block| Trie::new(block.split(", ")))
+        .unwrap();
+    let designs = sections
+        .parse("designs", |block| {
+            block.lines().map(str::to_owned).collect()
+        })
+        .unwrap();
+    (trie, designs)
+}
+
+// Returns `None` if the count does not fit in a `usize`.
+fn arrangements(trie: &Trie, design: &str) -> Option<usize> {
+    let design = design.as_bytes();
+    let mut ways = vec![0usize; design.len() + 1];
+    ways[0] = 1;
+    for i in 0..design.len() {
+        if ways[i] == 0 {
+            continue;
+        }
+        for len in trie.prefixes(&design[i..]) {
+            ways[i + len] = ways[i + len].checked_add(ways[i])?;
+        }
+    }
+    Some(ways[design.len()])
+}
+
+pub fn puzzle1(input: &str) -> usize {
+    let (trie, designs) = parse(input);
+    designs
+        .iter()
+        .filter(|design| arrangements(&trie, design) != Some(0))
+        .count()
+}
+
+pub fn puzzle2(input: &str) -> usize {
+    let (trie, designs) = parse(input);
+    designs
+        .iter()
+        .map(|design| arrangements(&trie, design).expect("arrangement count overflowed"))
+        .try_fold(0usize, usize::checked_add)
+        .expect("total arrangement count overflowed")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_puzzle1_example() {
+        assert_eq!(puzzle1(EXAMPLE), 6);
+    }
+
+    #[test]
+    fn test_puzzle2_example() {
+        assert_eq!(puzzle2(EXAMPLE), 16);
+    }
+
+    #[test]
+    fn test_arrangements_overflow() {
+        let trie = Trie::new(["a", "aa"]);
+        assert_eq!(arrangements(&trie, &"a".repeat(10)), Some(89));
+        assert_eq!(arrangements(&trie, &"a".repeat(200)), None);
+    }
+}
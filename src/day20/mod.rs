@@ -0,0 +1,66 @@
+use crate::{grid::Grid, search::bfs};
+
+fn track(input: &str) -> Grid<Option<usize>> {
+    let grid = Grid::parse(input);
+    let start = grid.position(|&c| c == b'S').unwrap();
+    let search = bfs([start], |(x, y)| {
+        grid.neighbors4(x, y)
+            .filter(|&(x, y)| grid.get(x, y) != Some(&b'#'))
+    });
+    let mut dist = grid.map(|_| None);
+    for (&(x, y), &d) in search.distances() {
+        *dist.get_mut(x, y).unwrap() = Some(d);
+    }
+    dist
+}
+
+pub fn cheats(input: &str, max_cheat: usize, threshold: usize) -> usize {
+    let dist = track(input);
+    let max = max_cheat as isize;
+    let mut count = 0;
+    for (x, y) in dist.points() {
+        let Some(&Some(from)) = dist.get(x, y) else {
+            continue;
+        };
+        for dy in -max..=max {
+            let span = max - dy.abs();
+            for dx in -span..=span {
+                if let Some(&Some(to)) = dist.get(x + dx, y + dy) {
+                    let skipped = dx.unsigned_abs() + dy.unsigned_abs();
+                    if to >= from + skipped + threshold {
+                        count += 1;
+                    }
+                }
+            }
+        }
+    }
+    count
+}
+
+pub fn puzzle1(input: &str) -> usize {
+    cheats(input, 2, 100)
+}
+
+pub fn puzzle2(input: &str) -> usize {
+    cheats(input, 20, 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_puzzle1_example() {
+        assert_eq!(cheats(EXAMPLE, 2, 64), 1);
+        assert_eq!(cheats(EXAMPLE, 2, 20), 5);
+        assert_eq!(cheats(EXAMPLE, 2, 2), 44);
+    }
+
+    #[test]
+    fn test_puzzle2_example() {
+        assert_eq!(cheats(EXAMPLE, 20, 76), 3);
+        assert_eq!(cheats(EXAMPLE, 20, 50), 285);
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use crate::{
+    grid::{Grid, Point},
+    scan::Scan,
+};
+
+const NUMERIC: &str = "789\n456\n123\n#0A\n";
+const DIRECTIONAL: &str = "#^A\n<v>\n";
+
+struct Keypad {
+    keys: HashMap<u8, Point>,
+    gap: Point,
+}
+
+impl Keypad {
+    fn new(layout: &str) -> Self {
+        let grid = Grid::parse(layout);
+        let keys = grid
+            .points()
+            .map(|(x, y)| (*grid.get(x, y).unwrap(), (x, y)))
+            .collect();
+        let gap = grid.position(|&c| c == b'#').unwrap();
+        Self { keys, gap }
+    }
+
+    // The two L-shaped ways to get from one key to another, skipping any through the gap.
+    fn routes(&self, from: u8, to: u8) -> Vec<Vec<u8>> {
+        let (x0, y0) = self.keys[&from];
+        let (x1, y1) = self.keys[&to];
+        let horizontal = [if x1 < x0 { b'<' } else { b'>' }].repeat(x0.abs_diff(x1));
+        let vertical = [if y1 < y0 { b'^' } else { b'v' }].repeat(y0.abs_diff(y1));
+        let mut routes = vec![];
+        if (x1, y0) != self.gap {
+            routes.push([&horizontal[..], &vertical, b"A"].concat());
+        }
+        if (x0, y1) != self.gap {
+            routes.push([&vertical[..], &horizontal, b"A"].concat());
+        }
+        routes.dedup();
+        routes
+    }
+}
+
+struct Chain {
+    directional: Keypad,
+    memo: HashMap<(u8, u8, usize), usize>,
+}
+
+impl Chain {
+    fn presses(&mut self, sequence: &[u8], depth: usize) -> usize {
+        let mut from = b'A';
+        let mut total = 0;
+        for &to in sequence {
+            total += self.step(from, to, depth);
+            from = to;
+        }
+        total
+    }
+
+    fn step(&mut self, from: u8, to: u8, depth: usize) -> usize {
+        if depth == 0 {
+            return 1;
+        }
+        if let Some(&n) = self.memo.get(&(from, to, depth)) {
+            return n;
+        }
+        let n = self
+            .directional
+            .routes(from, to)
+            .into_iter()
+            .map(|route| self.presses(&route, depth - 1))
+            .min()
+            .unwrap();
+        self.memo.insert((from, to, depth), n);
+        n
+    }
+}
+
+pub fn complexity(input: &str, robots: usize) -> usize {
+    let numeric = Keypad::new(NUMERIC);
+    let mut chain = Chain {
+        directional: Keypad::new(DIRECTIONAL),
+        memo: HashMap::new(),
+    };
+    input
+        .lines()
+        .map(|code| {
+            let mut from = b'A';
+            let mut length = 0;
+            for to in code.bytes() {
+                length += numeric
+                    .routes(from, to)
+                    .into_iter()
+                    .map(|route| chain.presses(&route, robots))
+                    .min()
+                    .unwrap();
+                from = to;
+            }
+            let [value]: [usize; 1] = code.ints().unwrap();
+            length * value
+        })
+        .sum()
+}
+
+pub fn puzzle1(input: &str) -> usize {
+    complexity(input, 2)
+}
+
+pub fn puzzle2(input: &str) -> usize {
+    complexity(input, 25)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_puzzle1_example() {
+        assert_eq!(puzzle1(EXAMPLE), 126384);
+    }
+
+    #[test]
+    fn test_puzzle2_example() {
+        assert_eq!(puzzle2(EXAMPLE), 154115708116294);
+    }
+
+    #[test]
+    fn test_chain_lengths() {
+        assert_eq!(complexity("029A", 0), 12 * 29);
+        assert_eq!(complexity("029A", 1), 28 * 29);
+        assert_eq!(complexity("029A", 2), 68 * 29);
+    }
+}
@@ -0,0 +1,101 @@
+use std::thread;
+
+use crate::{scan::Scan, visit::Visited};
+
+const ROUNDS: usize = 2000;
+// Price changes lie in -9..=9, so a window of four fits in 19^4 slots.
+const BASE: usize = 19;
+
+fn parse(input: &str) -> Vec<u64> {
+    input.all_ints().unwrap()
+}
+
+fn next(mut secret: u64) -> u64 {
+    const PRUNE: u64 = (1 << 24) - 1;
+    secret = (secret ^ secret << 6) & PRUNE;
+    secret = (secret ^ secret >> 5) & PRUNE;
+    (secret ^ secret << 11) & PRUNE
+}
+
+pub fn puzzle1(input: &str) -> u64 {
+    parse(input)
+        .into_iter()
+        .map(|secret| (0..ROUNDS).fold(secret, |s, _| next(s)))
+        .sum()
+}
+
+// Adds each buyer's price at the first occurrence of every change window.
+fn tally(buyers: &[u64]) -> Vec<u32> {
+    let mut totals = vec![0; BASE.pow(4)];
+    let mut seen = Visited::with_len(BASE.pow(4));
+    for &secret in buyers {
+        seen.clear();
+        let mut secret = secret;
+        let mut price = secret % 10;
+        let mut window = 0;
+        for i in 0..ROUNDS {
+            secret = next(secret);
+            let current = secret % 10;
+            window = (window * BASE + (9 + current - price) as usize) % BASE.pow(4);
+            price = current;
+            if i >= 3 && seen.insert_index(window) {
+                totals[window] += price as u32;
+            }
+        }
+    }
+    totals
+}
+
+pub fn puzzle2(input: &str) -> u32 {
+    let buyers = parse(input);
+    let threads = thread::available_parallelism().map_or(1, usize::from);
+    let chunk = buyers.len().div_ceil(threads).max(1);
+    let mut totals = vec![0; BASE.pow(4)];
+    thread::scope(|scope| {
+        let handles: Vec<_> = buyers
+            .chunks(chunk)
+            .map(|buyers| scope.spawn(|| tally(buyers)))
+            .collect();
+        for handle in handles {
+            for (total, n) in totals.iter_mut().zip(handle.join().unwrap()) {
+                *total += n;
+            }
+        }
+    });
+    totals.into_iter().max().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example1.txt");
+    const EXAMPLE2: &str = include_str!("example2.txt");
+
+    #[test]
+    fn test_puzzle1_example1() {
+        assert_eq!(puzzle1(EXAMPLE1), 37327623);
+    }
+
+    #[test]
+    fn test_puzzle2_example2() {
+        assert_eq!(puzzle2(EXAMPLE2), 23);
+    }
+
+    #[test]
+    fn test_next() {
+        let secrets: Vec<_> = (0..10)
+            .scan(123, |s, _| {
+                *s = next(*s);
+                Some(*s)
+            })
+            .collect();
+        assert_eq!(
+            secrets,
+            [
+                15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+                5908254,
+            ]
+        );
+    }
+}
@@ -0,0 +1,48 @@
+use itertools::Itertools;
+
+use crate::graph::Graph;
+
+fn parse(input: &str) -> Graph {
+    let mut graph = Graph::new();
+    for line in input.lines() {
+        let (a, b) = line.split_once('-').unwrap();
+        graph.add_edge(a, b);
+    }
+    graph
+}
+
+pub fn puzzle1(input: &str) -> usize {
+    let graph = parse(input);
+    graph
+        .triangles()
+        .into_iter()
+        .filter(|triangle| triangle.iter().any(|&id| graph.name(id).starts_with('t')))
+        .count()
+}
+
+pub fn puzzle2(input: &str) -> String {
+    let graph = parse(input);
+    graph
+        .maximum_clique()
+        .into_iter()
+        .map(|id| graph.name(id))
+        .sorted()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_puzzle1_example() {
+        assert_eq!(puzzle1(EXAMPLE), 7);
+    }
+
+    #[test]
+    fn test_puzzle2_example() {
+        assert_eq!(puzzle2(EXAMPLE), "co,de,ka,ta");
+    }
+}
@@ -0,0 +1,315 @@
+use std::{collections::HashMap, fmt};
+
+use itertools::Itertools;
+
+use crate::input::Input;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Op::And => "AND",
+            Op::Or => "OR",
+            Op::Xor => "XOR",
+        })
+    }
+}
+
+struct Gate<'a> {
+    inputs: [&'a str; 2],
+    op: Op,
+    output: &'a str,
+}
+
+struct Circuit<'a> {
+    initial: Vec<(&'a str, bool)>,
+    gates: Vec<Gate<'a>>,
+}
+
+fn parse<'a>(input: &'a Input) -> Circuit<'a> {
+    let mut sections = input.sections();
+    let initial = sections
+        .parse("wires", |block| {
+            block
+                .lines()
+                .map(|line| {
+                    let (wire, value) = line.split_once(": ").unwrap();
+                    (wire, value == "1")
+                })
+                .collect()
+        })
+        .unwrap();
+    let gates = sections
+        .parse("gates", |block| {
+            block
+                .lines()
+                .map(|line| {
+                    let (a, op, b, _, output) = line.split(' ').collect_tuple().unwrap();
+                    let op = match op {
+                        "AND" => Op::And,
+                        "OR" => Op::Or,
+                        "XOR" => Op::Xor,
+                        _ => panic!("unknown gate {op}"),
+                    };
+                    Gate {
+                        inputs: [a, b],
+                        op,
+                        output,
+                    }
+                })
+                .collect()
+        })
+        .unwrap();
+    Circuit { initial, gates }
+}
+
+impl<'a> Circuit<'a> {
+    fn evaluate(&self) -> HashMap<&'a str, bool> {
+        let mut values: HashMap<_, _> = self.initial.iter().copied().collect();
+        let mut pending: Vec<_> = self.gates.iter().collect();
+        while !pending.is_empty() {
+            let before = pending.len();
+            pending.retain(|gate| {
+                let [Some(&a), Some(&b)] = gate.inputs.map(|wire| values.get(wire)) else {
+                    return true;
+                };
+                let value = match gate.op {
+                    Op::And => a & b,
+                    Op::Or => a | b,
+                    Op::Xor => a ^ b,
+                };
+                values.insert(gate.output, value);
+                false
+            });
+            assert!(
+                pending.len() < before,
+                "circuit has a cycle or dangling wire"
+            );
+        }
+        values
+    }
+}
+
+fn number(values: &HashMap<&str, bool>, prefix: char) -> u64 {
+    values
+        .iter()
+        .filter(|(wire, &value)| wire.starts_with(prefix) && value)
+        .map(|(wire, _)| 1 << wire[1..].parse::<u32>().unwrap())
+        .sum()
+}
+
+pub fn puzzle1(input: &str) -> u64 {
+    let input = Input::new(input);
+    number(&parse(&input).evaluate(), 'z')
+}
+
+// The ways a gate can deviate from a ripple-carry adder built out of full adders.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+    // Every z output except the last is a sum bit, so it must come from an XOR.
+    SumNotXor,
+    // The last z output is the final carry, so it must come from an OR.
+    FinalCarryNotOr,
+    // An XOR of intermediate signals is a sum bit, so it must drive a z output.
+    XorNotSum,
+    // The half sum `xN XOR yN` must feed the XOR producing `zN`.
+    HalfSumUnused,
+    // Both carry terms of a full adder must feed its carry OR.
+    AndNotCarried,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    pub output: String,
+    pub rule: Rule,
+}
+
+pub fn check(input: &str) -> Vec<Violation> {
+    let input = Input::new(input);
+    let Circuit { gates, .. } = parse(&input);
+    let last = gates
+        .iter()
+        .map(|gate| gate.output)
+        .filter(|wire| wire.starts_with('z'))
+        .max()
+        .unwrap();
+    let feeds = |wire, op| {
+        gates
+            .iter()
+            .any(|gate| gate.op == op && gate.inputs.contains(&wire))
+    };
+    gates
+        .iter()
+        .filter_map(|gate| {
+            let from_inputs = gate.inputs.iter().all(|w| w.starts_with(['x', 'y']));
+            let first = gate.inputs.contains(&"x00");
+            let sum = gate.output.starts_with('z');
+            let rule = if gate.output == last {
+                (gate.op != Op::Or).then_some(Rule::FinalCarryNotOr)
+            } else if sum && gate.op != Op::Xor {
+                Some(Rule::SumNotXor)
+            } else {
+                match gate.op {
+                    Op::Xor if !from_inputs && !sum => Some(Rule::XorNotSum),
+                    Op::Xor if from_inputs && !first && !feeds(gate.output, Op::Xor) => {
+                        Some(Rule::HalfSumUnused)
+                    }
+                    Op::And if !first && !feeds(gate.output, Op::Or) => Some(Rule::AndNotCarried),
+                    _ => None,
+                }
+            };
+            rule.map(|rule| Violation {
+                output: gate.output.to_string(),
+                rule,
+            })
+        })
+        .collect()
+}
+
+pub fn puzzle2(input: &str) -> String {
+    check(input)
+        .into_iter()
+        .map(|violation| violation.output)
+        .sorted()
+        .dedup()
+        .join(",")
+}
+
+// Renders the circuit as a Graphviz digraph, one node per wire labelled with its gate.
+pub fn dot(input: &str) -> String {
+    let input = Input::new(input);
+    let circuit = parse(&input);
+    let mut lines = vec!["digraph circuit {".to_string()];
+    for (wire, _) in &circuit.initial {
+        lines.push(format!("    {wire} [shape=box];"));
+    }
+    for gate in &circuit.gates {
+        let [a, b] = gate.inputs;
+        let output = gate.output;
+        lines.push(format!("    {output} [label=\"{output}\\n{}\"];", gate.op));
+        lines.push(format!("    {a} -> {output};"));
+        lines.push(format!("    {b} -> {output};"));
+    }
+    lines.push("}\n".to_string());
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = include_str!("example1.txt");
+    const EXAMPLE2: &str = include_str!("example2.txt");
+
+    #[test]
+    fn test_puzzle1_example1() {
+        assert_eq!(puzzle1(EXAMPLE1), 4);
+    }
+
+    #[test]
+    fn test_puzzle1_example2() {
+        assert_eq!(puzzle1(EXAMPLE2), 2024);
+    }
+
+    // A ripple-carry adder over `bits` bits with the given pairs of gate outputs swapped.
+    fn adder(bits: usize, x: u64, y: u64, swaps: &[(&str, &str)]) -> String {
+        let mut lines = vec![];
+        for (prefix, value) in [('x', x), ('y', y)] {
+            for i in 0..bits {
+                lines.push(format!("{prefix}{i:02}: {}", value >> i & 1));
+            }
+        }
+        lines.push(String::new());
+        let mut gates = vec![
+            "x00 XOR y00 -> z00".to_string(),
+            "x00 AND y00 -> c00".to_string(),
+        ];
+        for i in 1..bits {
+            let carry_in = format!("c{:02}", i - 1);
+            let carry_out = if i == bits - 1 {
+                format!("z{bits:02}")
+            } else {
+                format!("c{i:02}")
+            };
+            gates.extend([
+                format!("x{i:02} XOR y{i:02} -> s{i:02}"),
+                format!("x{i:02} AND y{i:02} -> g{i:02}"),
+                format!("s{i:02} XOR {carry_in} -> z{i:02}"),
+                format!("s{i:02} AND {carry_in} -> t{i:02}"),
+                format!("g{i:02} OR t{i:02} -> {carry_out}"),
+            ]);
+        }
+        for gate in gates {
+            let (lhs, output) = gate.split_once(" -> ").unwrap();
+            let output = swaps
+                .iter()
+                .find_map(|&(p, q)| match output {
+                    _ if output == p => Some(q),
+                    _ if output == q => Some(p),
+                    _ => None,
+                })
+                .unwrap_or(output);
+            lines.push(format!("{lhs} -> {output}"));
+        }
+        lines.join("\n") + "\n"
+    }
+
+    #[test]
+    fn test_adder_evaluates() {
+        let input = adder(8, 173, 94, &[]);
+        assert_eq!(puzzle1(&input), 173 + 94);
+        assert_eq!(check(&input), []);
+    }
+
+    #[test]
+    fn test_check_swapped_adder() {
+        let input = adder(10, 0, 0, &[("z05", "c05"), ("s07", "g07")]);
+        let violations = check(&input);
+        let violations: Vec<_> = violations
+            .iter()
+            .map(|v| (v.output.as_str(), v.rule))
+            .sorted_by_key(|&(output, _)| output)
+            .collect();
+        assert_eq!(
+            violations,
+            [
+                ("c05", Rule::XorNotSum),
+                ("g07", Rule::HalfSumUnused),
+                ("s07", Rule::AndNotCarried),
+                ("z05", Rule::SumNotXor),
+            ]
+        );
+        assert_eq!(puzzle2(&input), "c05,g07,s07,z05");
+    }
+
+    #[test]
+    fn test_dot_example1() {
+        assert_eq!(
+            dot(EXAMPLE1),
+            r#"digraph circuit {
+    x00 [shape=box];
+    x01 [shape=box];
+    x02 [shape=box];
+    y00 [shape=box];
+    y01 [shape=box];
+    y02 [shape=box];
+    z00 [label="z00\nAND"];
+    x00 -> z00;
+    y00 -> z00;
+    z01 [label="z01\nXOR"];
+    x01 -> z01;
+    y01 -> z01;
+    z02 [label="z02\nOR"];
+    x02 -> z02;
+    y02 -> z02;
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{grid::Grid, input::Input};
+
+struct Schematic {
+    lock: bool,
+    heights: Vec<usize>,
+}
+
+impl Schematic {
+    fn new(block: &str) -> Self {
+        let grid = Grid::parse(block);
+        let lock = grid.get(0, 0) == Some(&b'#');
+        // Don't count the solid row that only marks which kind of schematic this is.
+        let heights = (0..grid.width() as isize)
+            .map(|x| {
+                let filled = (0..grid.height() as isize)
+                    .filter(|&y| grid.get(x, y) == Some(&b'#'))
+                    .count();
+                filled - 1
+            })
+            .collect();
+        Self { lock, heights }
+    }
+}
+
+pub fn puzzle1(input: &str) -> usize {
+    let input = Input::new(input);
+    let schematics: Vec<_> = input.sections().map(Schematic::new).collect();
+    let space = input
+        .sections()
+        .next()
+        .map_or(0, |block| block.lines().count() - 2);
+    let (locks, keys): (Vec<_>, Vec<_>) = schematics.iter().partition(|s| s.lock);
+    locks
+        .iter()
+        .flat_map(|lock| keys.iter().map(move |key| (lock, key)))
+        .filter(|(lock, key)| {
+            lock.heights
+                .iter()
+                .zip(&key.heights)
+                .all(|(l, k)| l + k <= space)
+        })
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("example.txt");
+
+    #[test]
+    fn test_puzzle1_example() {
+        assert_eq!(puzzle1(EXAMPLE), 3);
+    }
+
+    #[test]
+    fn test_heights() {
+        let lock = Schematic::new("#####\n.####\n.####\n.####\n.#.#.\n.#...\n.....");
+        assert!(lock.lock);
+        assert_eq!(lock.heights, [0, 5, 3, 4, 3]);
+        let key = Schematic::new(".....\n#....\n#....\n#...#\n#.#.#\n#.###\n#####");
+        assert!(!key.lock);
+        assert_eq!(key.heights, [5, 0, 2, 1, 3]);
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::HashMap;
+
+#[derive(Clone, Debug, Default)]
+pub struct Bits {
+    words: Vec<u64>,
+}
+
+// Sets can carry trailing zero words after removals, so compare as if padded with zeros.
+impl PartialEq for Bits {
+    fn eq(&self, other: &Self) -> bool {
+        let n = self.words.len().max(other.words.len());
+        (0..n).all(|i| self.words.get(i).unwrap_or(&0) == other.words.get(i).unwrap_or(&0))
+    }
+}
+
+impl Eq for Bits {}
+
+impl Bits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        self.words
+            .get(i / 64)
+            .is_some_and(|w| w & (1 << (i % 64)) != 0)
+    }
+
+    pub fn insert(&mut self, i: usize) -> bool {
+        let w = i / 64;
+        if w >= self.words.len() {
+            self.words.resize(w + 1, 0);
+        }
+        let bit = 1 << (i % 64);
+        let fresh = self.words[w] & bit == 0;
+        self.words[w] |= bit;
+        fresh
+    }
+
+    pub fn remove(&mut self, i: usize) -> bool {
+        match self.words.get_mut(i / 64) {
+            Some(w) => {
+                let bit = 1 << (i % 64);
+                let present = *w & bit != 0;
+                *w &= !bit;
+                present
+            }
+            None => false,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    pub fn intersection(&self, other: &Bits) -> Bits {
+        let words = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| a & b)
+            .collect();
+        Bits { words }
+    }
+
+    pub fn difference(&self, other: &Bits) -> Bits {
+        let words = self
+            .words
+            .iter()
+            .enumerate()
+            .map(|(i, a)| a & !other.words.get(i).unwrap_or(&0))
+            .collect();
+        Bits { words }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &w)| {
+            let mut w = w;
+            std::iter::from_fn(move || {
+                (w != 0).then(|| {
+                    let bit = w.trailing_zeros() as usize;
+                    w &= w - 1;
+                    64 * i + bit
+                })
+            })
+        })
+    }
+}
+
+impl FromIterator<usize> for Bits {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut bits = Bits::new();
+        for i in iter {
+            bits.insert(i);
+        }
+        bits
+    }
+}
+
+// An undirected graph over interned node names.
+#[derive(Clone, Debug, Default)]
+pub struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    adjacency: Vec<Bits>,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.adjacency.push(Bits::new());
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    pub fn add_edge(&mut self, a: &str, b: &str) {
+        let (a, b) = (self.intern(a), self.intern(b));
+        self.adjacency[a].insert(b);
+        self.adjacency[b].insert(a);
+    }
+
+    pub fn neighbors(&self, id: usize) -> &Bits {
+        &self.adjacency[id]
+    }
+
+    pub fn adjacent(&self, a: usize, b: usize) -> bool {
+        self.adjacency[a].contains(b)
+    }
+
+    // Every triangle once, with ids in increasing order.
+    pub fn triangles(&self) -> Vec<[usize; 3]> {
+        let mut triangles = vec![];
+        for a in 0..self.len() {
+            for b in self.adjacency[a].iter().filter(|&b| b > a) {
+                let common = self.adjacency[a].intersection(&self.adjacency[b]);
+                triangles.extend(common.iter().filter(|&c| c > b).map(|c| [a, b, c]));
+            }
+        }
+        triangles
+    }
+
+    // Calls `report` with each maximal clique (Bron–Kerbosch with pivoting).
+    pub fn maximal_cliques(&self, mut report: impl FnMut(&[usize])) {
+        let everyone = (0..self.len()).collect();
+        self.bron_kerbosch(&mut vec![], everyone, Bits::new(), &mut report);
+    }
+
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<usize>,
+        mut candidates: Bits,
+        mut excluded: Bits,
+        report: &mut impl FnMut(&[usize]),
+    ) {
+        let Some(pivot) = candidates
+            .iter()
+            .chain(excluded.iter())
+            .max_by_key(|&u| self.adjacency[u].intersection(&candidates).len())
+        else {
+            report(clique);
+            return;
+        };
+        for v in candidates.difference(&self.adjacency[pivot]).iter() {
+            clique.push(v);
+            self.bron_kerbosch(
+                clique,
+                candidates.intersection(&self.adjacency[v]),
+                excluded.intersection(&self.adjacency[v]),
+                report,
+            );
+            clique.pop();
+            candidates.remove(v);
+            excluded.insert(v);
+        }
+    }
+
+    pub fn maximum_clique(&self) -> Vec<usize> {
+        let mut best = vec![];
+        self.maximal_cliques(|clique| {
+            if clique.len() > best.len() {
+                best = clique.to_vec();
+            }
+        });
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bits() {
+        let mut bits: Bits = [3, 64, 130].into_iter().collect();
+        assert!(bits.contains(64));
+        assert!(!bits.insert(3));
+        assert!(bits.remove(130));
+        assert!(!bits.remove(500));
+        assert_eq!(bits.len(), 2);
+        let other: Bits = [3, 5].into_iter().collect();
+        assert_eq!(bits.intersection(&other).iter().collect::<Vec<_>>(), [3]);
+        assert_eq!(bits.difference(&other).iter().collect::<Vec<_>>(), [64]);
+    }
+
+    #[test]
+    fn test_bits_equality() {
+        let mut bits: Bits = [3, 130].into_iter().collect();
+        bits.remove(130);
+        assert_eq!(bits, [3].into_iter().collect());
+        let wide: Bits = [3, 200].into_iter().collect();
+        let narrow: Bits = [3].into_iter().collect();
+        assert_eq!(wide.difference(&[200].into_iter().collect()), narrow);
+        assert_ne!(wide, narrow);
+        assert_eq!(Bits::new(), wide.intersection(&[5].into_iter().collect()));
+    }
+
+    #[test]
+    fn test_cliques() {
+        let mut graph = Graph::new();
+        for (a, b) in [("a", "b"), ("b", "c"), ("a", "c"), ("c", "d"), ("d", "e")] {
+            graph.add_edge(a, b);
+        }
+        assert_eq!(graph.triangles(), [[0, 1, 2]]);
+        let mut cliques = vec![];
+        graph.maximal_cliques(|clique| {
+            let mut names: Vec<_> = clique.iter().map(|&id| graph.name(id)).collect();
+            names.sort();
+            cliques.push(names.join(","));
+        });
+        cliques.sort();
+        assert_eq!(cliques, ["a,b,c", "c,d", "d,e"]);
+        assert_eq!(graph.maximum_clique().len(), 3);
+    }
+}
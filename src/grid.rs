@@ -0,0 +1,236 @@
+use std::fmt;
+
+pub type Point = (isize, isize);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+    wrapping: bool,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        let cells = vec![fill; width * height];
+        Self {
+            width,
+            height,
+            cells,
+            wrapping: false,
+        }
+    }
+}
+
+impl Grid<u8> {
+    pub fn parse(input: &str) -> Self {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(input.len());
+        for line in input.lines() {
+            let w = *width.get_or_insert(line.len());
+            assert_eq!(line.len(), w, "ragged grid row: {line:?}");
+            cells.extend_from_slice(line.as_bytes());
+        }
+        let width = width.unwrap_or(0);
+        let height = if width == 0 { 0 } else { cells.len() / width };
+        Self {
+            width,
+            height,
+            cells,
+            wrapping: false,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn wrapping(mut self) -> Self {
+        self.wrapping = true;
+        self
+    }
+
+    pub fn is_wrapping(&self) -> bool {
+        self.wrapping
+    }
+
+    pub fn wrap(&self, x: isize, y: isize) -> Point {
+        if self.wrapping && self.width > 0 && self.height > 0 {
+            (
+                x.rem_euclid(self.width as isize),
+                y.rem_euclid(self.height as isize),
+            )
+        } else {
+            (x, y)
+        }
+    }
+
+    fn index(&self, x: isize, y: isize) -> Option<usize> {
+        let (x, y) = self.wrap(x, y);
+        let i: usize = y.try_into().ok()?;
+        let j: usize = x.try_into().ok()?;
+        if i >= self.height || j >= self.width {
+            return None;
+        }
+        Some(i * self.width + j)
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        let k = self.index(x, y)?;
+        Some(&self.cells[k])
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        let k = self.index(x, y)?;
+        Some(&mut self.cells[k])
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as isize;
+        (0..self.height as isize).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<Point> {
+        let k = self.cells.iter().position(pred)?;
+        Some(((k % self.width) as isize, (k / self.width) as isize))
+    }
+
+    // On a narrow torus several offsets can wrap onto the same cell, or back onto the
+    // cell itself, so each neighbor is only reported the first time it comes up.
+    fn around(
+        &self,
+        x: isize,
+        y: isize,
+        offsets: &'static [Point],
+    ) -> impl Iterator<Item = Point> + '_ {
+        let origin = self.wrap(x, y);
+        let at = move |(dx, dy): Point| self.wrap(x + dx, y + dy);
+        offsets
+            .iter()
+            .enumerate()
+            .map(move |(i, &offset)| (i, at(offset)))
+            .filter(move |&(i, (nx, ny))| {
+                self.index(nx, ny).is_some()
+                    && (!self.wrapping
+                        || ((nx, ny) != origin && offsets[..i].iter().all(|&o| at(o) != (nx, ny))))
+            })
+            .map(|(_, point)| point)
+    }
+
+    pub fn neighbors4(&self, x: isize, y: isize) -> impl Iterator<Item = Point> + '_ {
+        self.around(x, y, &[(1, 0), (0, -1), (-1, 0), (0, 1)])
+    }
+
+    pub fn neighbors8(&self, x: isize, y: isize) -> impl Iterator<Item = Point> + '_ {
+        self.around(
+            x,
+            y,
+            &[
+                (1, 0),
+                (1, -1),
+                (0, -1),
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        )
+    }
+
+    pub fn ray(
+        &self,
+        x: isize,
+        y: isize,
+        dx: isize,
+        dy: isize,
+    ) -> impl Iterator<Item = Point> + '_ {
+        assert_ne!((dx, dy), (0, 0));
+        let origin = self.wrap(x, y);
+        (1..)
+            .map(move |k| self.wrap(x + k * dx, y + k * dy))
+            .take_while(move |&(x, y)| self.index(x, y).is_some() && (x, y) != origin)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+            wrapping: self.wrapping,
+        }
+    }
+}
+
+impl fmt::Display for Grid<u8> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            writeln!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::search::bfs;
+
+    #[test]
+    fn test_parse_crlf() {
+        let grid = Grid::parse("ab\r\ncd\r\n");
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid.get(1, 1), Some(&b'd'));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, -1), None);
+        assert_eq!(grid.to_string(), "ab\ncd\n");
+    }
+
+    #[test]
+    fn test_neighbors_in_bounds() {
+        let grid = Grid::new(3, 2, ());
+        assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), [(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbors8(1, 1).count(), 5);
+    }
+
+    #[test]
+    fn test_ray_stops_at_edge() {
+        let grid = Grid::new(3, 2, ());
+        assert_eq!(grid.ray(0, 0, 1, 1).collect::<Vec<_>>(), [(1, 1)]);
+        assert_eq!(grid.ray(0, 1, 1, 0).count(), 2);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = Grid::parse("ab\ncd\nef\n").wrapping();
+        assert_eq!(grid.get(-1, -1), Some(&b'f'));
+        assert_eq!(grid.get(2, 4), Some(&b'c'));
+        assert_eq!(grid.wrap(-3, 7), (1, 1));
+        assert_eq!(
+            grid.neighbors4(0, 0).collect::<Vec<_>>(),
+            [(1, 0), (0, 2), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8(0, 0).count(), 5);
+        assert_eq!(grid.ray(0, 0, 1, 1).count(), 5);
+        assert_eq!(grid.ray(0, 0, 0, -1).collect::<Vec<_>>(), [(0, 2), (0, 1)]);
+    }
+
+    #[test]
+    fn test_wrapping_narrow() {
+        let grid = Grid::new(2, 1, 0).wrapping();
+        assert_eq!(grid.neighbors4(0, 0).collect::<Vec<_>>(), [(1, 0)]);
+        assert_eq!(grid.neighbors8(1, 0).collect::<Vec<_>>(), [(0, 0)]);
+        let grid = Grid::new(1, 1, 0).wrapping();
+        assert_eq!(grid.neighbors8(0, 0).count(), 0);
+        let grid = Grid::new(2, 1, 0).wrapping();
+        let search = bfs([(0, 0)], |(x, y)| grid.neighbors4(x, y));
+        assert_eq!(search.predecessors((1, 0)), [(0, 0)]);
+        assert_eq!(search.paths((1, 0)).len(), 1);
+    }
+}
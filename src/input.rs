@@ -0,0 +1,119 @@
+use std::{borrow::Cow, error::Error, fmt};
+
+pub struct Input<'a> {
+    text: Cow<'a, str>,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(raw: &'a str) -> Self {
+        let text = if raw.contains('\r') {
+            Cow::Owned(raw.replace("\r\n", "\n").replace('\r', "\n"))
+        } else {
+            Cow::Borrowed(raw)
+        };
+        Self { text }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn sections(&self) -> Sections<'_> {
+        Sections {
+            rest: &self.text,
+            index: 0,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MissingSection {
+    pub name: &'static str,
+    pub index: usize,
+}
+
+impl fmt::Display for MissingSection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "missing section {} ({}): input has only {} blank-line-separated blocks",
+            self.index + 1,
+            self.name,
+            self.index,
+        )
+    }
+}
+
+impl Error for MissingSection {}
+
+pub struct Sections<'a> {
+    rest: &'a str,
+    index: usize,
+}
+
+impl<'a> Sections<'a> {
+    pub fn section(&mut self, name: &'static str) -> Result<&'a str, MissingSection> {
+        let index = self.index;
+        self.next().ok_or(MissingSection { name, index })
+    }
+
+    pub fn parse<T>(
+        &mut self,
+        name: &'static str,
+        f: impl FnOnce(&'a str) -> T,
+    ) -> Result<T, MissingSection> {
+        self.section(name).map(f)
+    }
+}
+
+impl<'a> Iterator for Sections<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut start = None;
+        let mut end = 0;
+        let mut offset = 0;
+        for line in self.rest.split_inclusive('\n') {
+            let blank = line.trim().is_empty();
+            match (start, blank) {
+                (None, true) => {}
+                (None, false) => {
+                    start = Some(offset);
+                    end = offset + line.trim_end_matches('\n').len();
+                }
+                (Some(_), false) => end = offset + line.trim_end_matches('\n').len(),
+                (Some(_), true) => break,
+            }
+            offset += line.len();
+        }
+        let block = &self.rest[start?..end];
+        self.rest = &self.rest[offset..];
+        self.index += 1;
+        Some(block)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocks() {
+        let input = Input::new("\r\na\r\nb\r\n\r\n\r\nc\r\n\r\n");
+        let mut sections = input.sections();
+        assert_eq!(sections.section("first"), Ok("a\nb"));
+        assert_eq!(sections.parse("second", str::len), Ok(1));
+        let err = sections.section("third").unwrap_err();
+        assert_eq!(
+            err,
+            MissingSection {
+                name: "third",
+                index: 2,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "missing section 3 (third): input has only 2 blank-line-separated blocks",
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use crate::grid::Grid;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Orientation {
+    pub flipped: bool,
+    pub quarter_turns: u8,
+}
+
+impl Orientation {
+    pub const IDENTITY: Self = Self {
+        flipped: false,
+        quarter_turns: 0,
+    };
+
+    fn all() -> impl Iterator<Item = Self> {
+        [false, true].into_iter().flat_map(|flipped| {
+            (0..4).map(move |quarter_turns| Self {
+                flipped,
+                quarter_turns,
+            })
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub x: isize,
+    pub y: isize,
+    pub orientation: Orientation,
+}
+
+#[derive(Clone, Debug)]
+struct Variant {
+    orientation: Orientation,
+    width: usize,
+    height: usize,
+    cells: Vec<(isize, isize, u8)>,
+}
+
+impl Variant {
+    fn transform(&self, orientation: Orientation) -> Self {
+        let (mut width, mut height) = (self.width, self.height);
+        let mut cells = self.cells.clone();
+        if orientation.flipped {
+            for (x, _, _) in &mut cells {
+                *x = width as isize - 1 - *x;
+            }
+        }
+        for _ in 0..orientation.quarter_turns {
+            for (x, y, _) in &mut cells {
+                (*x, *y) = (height as isize - 1 - *y, *x);
+            }
+            (width, height) = (height, width);
+        }
+        cells.sort_unstable();
+        Self {
+            orientation,
+            width,
+            height,
+            cells,
+        }
+    }
+
+    fn same_shape(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.cells == other.cells
+    }
+
+    fn matches_at(&self, grid: &Grid<u8>, x: isize, y: isize) -> bool {
+        self.cells
+            .iter()
+            .all(|&(dx, dy, c)| grid.get(x + dx, y + dy) == Some(&c))
+    }
+}
+
+pub struct Pattern {
+    variants: Vec<Variant>,
+}
+
+impl Pattern {
+    pub fn new(template: &str) -> Self {
+        let grid = Grid::parse(template);
+        let cells = grid
+            .points()
+            .filter_map(|(x, y)| match grid.get(x, y) {
+                Some(b'.' | b'?') | None => None,
+                Some(&c) => Some((x, y, c)),
+            })
+            .collect();
+        let variant = Variant {
+            orientation: Orientation::IDENTITY,
+            width: grid.width(),
+            height: grid.height(),
+            cells,
+        };
+        Self {
+            variants: vec![variant],
+        }
+    }
+
+    pub fn with_symmetries(self) -> Self {
+        let base = &self.variants[0];
+        let mut variants: Vec<Variant> = vec![];
+        for orientation in Orientation::all() {
+            let variant = base.transform(orientation);
+            if !variants.iter().any(|v| v.same_shape(&variant)) {
+                variants.push(variant);
+            }
+        }
+        Self { variants }
+    }
+
+    pub fn orientations(&self) -> impl Iterator<Item = Orientation> + '_ {
+        self.variants.iter().map(|v| v.orientation)
+    }
+
+    pub fn matches(&self, grid: &Grid<u8>) -> Vec<Match> {
+        let mut found = vec![];
+        for variant in &self.variants {
+            let (Some(w), Some(h)) = (
+                (grid.width() + 1).checked_sub(variant.width),
+                (grid.height() + 1).checked_sub(variant.height),
+            ) else {
+                continue;
+            };
+            for y in 0..h as isize {
+                for x in 0..w as isize {
+                    if variant.matches_at(grid, x, y) {
+                        found.push(Match {
+                            x,
+                            y,
+                            orientation: variant.orientation,
+                        });
+                    }
+                }
+            }
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symmetries_deduplicated() {
+        assert_eq!(
+            Pattern::new("XMAS")
+                .with_symmetries()
+                .orientations()
+                .count(),
+            4
+        );
+        assert_eq!(
+            Pattern::new("A?A").with_symmetries().orientations().count(),
+            2
+        );
+        assert_eq!(
+            Pattern::new("L.\nLL")
+                .with_symmetries()
+                .orientations()
+                .count(),
+            4
+        );
+        assert_eq!(
+            Pattern::new("L.\nL.\nLL")
+                .with_symmetries()
+                .orientations()
+                .count(),
+            8
+        );
+    }
+
+    #[test]
+    fn test_matches_report_orientation() {
+        let grid = Grid::parse("SAMX\n....\n");
+        let matches = Pattern::new("XMAS").with_symmetries().matches(&grid);
+        assert_eq!(
+            matches,
+            [Match {
+                x: 0,
+                y: 0,
+                orientation: Orientation {
+                    flipped: false,
+                    quarter_turns: 2,
+                },
+            }],
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crate::grid::{Grid, Point};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+#[derive(Clone, Debug)]
+pub struct Region {
+    pub cells: Vec<Point>,
+    pub perimeter: usize,
+    pub corners: usize,
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn sides(&self) -> usize {
+        self.corners
+    }
+}
+
+pub struct Regions {
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+fn neighbors<T>(
+    grid: &Grid<T>,
+    connectivity: Connectivity,
+    (x, y): Point,
+) -> Box<dyn Iterator<Item = Point> + '_> {
+    match connectivity {
+        Connectivity::Four => Box::new(grid.neighbors4(x, y)),
+        Connectivity::Eight => Box::new(grid.neighbors8(x, y)),
+    }
+}
+
+fn measure(labels: &Grid<usize>, label: usize, cells: Vec<Point>) -> Region {
+    let inside = |x, y| labels.get(x, y) == Some(&label);
+    let mut perimeter = 0;
+    let mut corners = 0;
+    let (mut min, mut max) = (cells[0], cells[0]);
+    for &(x, y) in &cells {
+        min = (min.0.min(x), min.1.min(y));
+        max = (max.0.max(x), max.1.max(y));
+        for (dx, dy) in [(1, 0), (0, -1), (-1, 0), (0, 1)] {
+            if !inside(x + dx, y + dy) {
+                perimeter += 1;
+            }
+        }
+        for (dx, dy) in [(1, 1), (1, -1), (-1, -1), (-1, 1)] {
+            let a = inside(x + dx, y);
+            let b = inside(x, y + dy);
+            if (!a && !b) || (a && b && !inside(x + dx, y + dy)) {
+                corners += 1;
+            }
+        }
+    }
+    Region {
+        cells,
+        perimeter,
+        corners,
+        min,
+        max,
+    }
+}
+
+pub fn label<T: PartialEq>(grid: &Grid<T>, connectivity: Connectivity) -> Regions {
+    // Mapping keeps the wrapping flag, so `measure` sees the same topology as the fill.
+    let mut labels = grid.map(|_| usize::MAX);
+    let mut members = vec![];
+    for (x0, y0) in grid.points() {
+        if labels.get(x0, y0) != Some(&usize::MAX) {
+            continue;
+        }
+        let label = members.len();
+        let value = grid.get(x0, y0);
+        let mut cells = vec![];
+        let mut stack = vec![(x0, y0)];
+        *labels.get_mut(x0, y0).unwrap() = label;
+        while let Some(p) = stack.pop() {
+            cells.push(p);
+            for (x, y) in neighbors(grid, connectivity, p) {
+                if grid.get(x, y) == value && labels.get(x, y) == Some(&usize::MAX) {
+                    *labels.get_mut(x, y).unwrap() = label;
+                    stack.push((x, y));
+                }
+            }
+        }
+        members.push(cells);
+    }
+    let regions = members
+        .into_iter()
+        .enumerate()
+        .map(|(label, cells)| measure(&labels, label, cells))
+        .collect();
+    Regions { labels, regions }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measurements() {
+        let grid = Grid::parse("AAAA\nBBCD\nBBCC\nEEEC\n");
+        let Regions { labels, regions } = label(&grid, Connectivity::Four);
+        assert_eq!(regions.len(), 5);
+        let c = &regions[*labels.get(2, 1).unwrap()];
+        assert_eq!((c.area(), c.perimeter, c.sides()), (4, 10, 8));
+        assert_eq!((c.min, c.max), ((2, 1), (3, 3)));
+        let a = &regions[*labels.get(0, 0).unwrap()];
+        assert_eq!((a.area(), a.perimeter, a.sides()), (4, 10, 4));
+    }
+
+    #[test]
+    fn test_diagonal_connectivity() {
+        let grid = Grid::parse("AB\nBA\n");
+        assert_eq!(label(&grid, Connectivity::Four).regions.len(), 4);
+        let Regions { regions, .. } = label(&grid, Connectivity::Eight);
+        assert_eq!(regions.len(), 2);
+        assert_eq!((regions[0].area(), regions[0].perimeter), (2, 8));
+    }
+
+    #[test]
+    fn test_wrapping_regions() {
+        let grid = Grid::parse("AB\nAB\n").wrapping();
+        let Regions { labels, regions } = label(&grid, Connectivity::Four);
+        assert!(labels.is_wrapping());
+        assert_eq!(regions.len(), 2);
+        for region in &regions {
+            assert_eq!((region.area(), region.perimeter), (2, 4));
+        }
+        let grid = Grid::parse("AAA\nABA\nAAA\n").wrapping();
+        let Regions { regions, .. } = label(&grid, Connectivity::Four);
+        assert_eq!((regions[0].area(), regions[0].perimeter), (8, 4));
+        assert_eq!((regions[1].area(), regions[1].perimeter), (1, 4));
+    }
+}
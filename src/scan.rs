@@ -0,0 +1,241 @@
+use std::{error::Error, fmt, marker::PhantomData};
+
+pub trait Int: Copy {
+    const SIGNED: bool;
+
+    fn accumulate(self, digit: u8, negative: bool) -> Option<Self>;
+
+    fn zero() -> Self;
+}
+
+macro_rules! impl_int {
+    ($signed:expr => $($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const SIGNED: bool = $signed;
+
+                fn accumulate(self, digit: u8, negative: bool) -> Option<Self> {
+                    let shifted = self.checked_mul(10)?;
+                    let digit = Self::try_from(digit).ok()?;
+                    if negative {
+                        shifted.checked_sub(digit)
+                    } else {
+                        shifted.checked_add(digit)
+                    }
+                }
+
+                fn zero() -> Self {
+                    0
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(false => u8, u16, u32, u64, u128, usize);
+impl_int!(true => i8, i16, i32, i64, i128, isize);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Number<T> {
+    pub value: T,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScanErrorKind {
+    Overflow,
+    Negative,
+    TooFew { expected: usize, found: usize },
+    TooMany { expected: usize },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScanError {
+    pub offset: usize,
+    pub kind: ScanErrorKind,
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "at byte {}: ", self.offset)?;
+        match self.kind {
+            ScanErrorKind::Overflow => write!(f, "integer out of range"),
+            ScanErrorKind::Negative => write!(f, "negative integer for an unsigned type"),
+            ScanErrorKind::TooFew { expected, found } => {
+                write!(f, "expected {expected} integers but found {found}")
+            }
+            ScanErrorKind::TooMany { expected } => {
+                write!(f, "expected {expected} integers but found more")
+            }
+        }
+    }
+}
+
+impl Error for ScanError {}
+
+pub struct Numbers<'a, T> {
+    bytes: &'a [u8],
+    pos: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: Int> Iterator for Numbers<'_, T> {
+    type Item = Result<Number<T>, ScanError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.bytes;
+        let first = self.pos + bytes[self.pos..].iter().position(u8::is_ascii_digit)?;
+        let minus = first > 0 && bytes[first - 1] == b'-';
+        let negative = T::SIGNED && minus;
+        // For unsigned types a `-` between two digits, as in `1-2`, is just a separator.
+        let rejected = !T::SIGNED && minus && (first < 2 || !bytes[first - 2].is_ascii_digit());
+        let start = if negative || rejected {
+            first - 1
+        } else {
+            first
+        };
+        let mut value = Some(T::zero());
+        let mut end = first;
+        while let Some(&c) = bytes.get(end).filter(|c| c.is_ascii_digit()) {
+            value = value.and_then(|v| v.accumulate(c - b'0', negative));
+            end += 1;
+        }
+        self.pos = end;
+        Some(match value {
+            _ if rejected => Err(ScanError {
+                offset: start,
+                kind: ScanErrorKind::Negative,
+            }),
+            Some(value) => Ok(Number { value, start, end }),
+            None => Err(ScanError {
+                offset: start,
+                kind: ScanErrorKind::Overflow,
+            }),
+        })
+    }
+}
+
+pub trait Scan {
+    fn numbers<T: Int>(&self) -> Numbers<'_, T>;
+
+    fn ints<T: Int, const N: usize>(&self) -> Result<[T; N], ScanError>;
+
+    fn all_ints<T: Int>(&self) -> Result<Vec<T>, ScanError>;
+}
+
+impl Scan for str {
+    fn numbers<T: Int>(&self) -> Numbers<'_, T> {
+        Numbers {
+            bytes: self.as_bytes(),
+            pos: 0,
+            marker: PhantomData,
+        }
+    }
+
+    fn ints<T: Int, const N: usize>(&self) -> Result<[T; N], ScanError> {
+        let mut values = [T::zero(); N];
+        let mut numbers = self.numbers();
+        for (i, value) in values.iter_mut().enumerate() {
+            match numbers.next() {
+                Some(number) => *value = number?.value,
+                None => {
+                    return Err(ScanError {
+                        offset: self.len(),
+                        kind: ScanErrorKind::TooFew {
+                            expected: N,
+                            found: i,
+                        },
+                    })
+                }
+            }
+        }
+        match numbers.next() {
+            None => Ok(values),
+            Some(number) => Err(ScanError {
+                offset: match number {
+                    Ok(number) => number.start,
+                    Err(err) => err.offset,
+                },
+                kind: ScanErrorKind::TooMany { expected: N },
+            }),
+        }
+    }
+
+    fn all_ints<T: Int>(&self) -> Result<Vec<T>, ScanError> {
+        self.numbers().map(|number| Ok(number?.value)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions_and_signs() {
+        let numbers: Vec<Number<i32>> = "p=-3,10 v=+4".numbers().map(Result::unwrap).collect();
+        assert_eq!(
+            numbers,
+            [
+                Number {
+                    value: -3,
+                    start: 2,
+                    end: 4,
+                },
+                Number {
+                    value: 10,
+                    start: 5,
+                    end: 7,
+                },
+                Number {
+                    value: 4,
+                    start: 11,
+                    end: 12,
+                },
+            ],
+        );
+        assert_eq!("1-2".all_ints::<u8>(), Ok(vec![1, 2]));
+        assert_eq!("1-2".all_ints::<i8>(), Ok(vec![1, -2]));
+        assert_eq!("-128".all_ints::<i8>(), Ok(vec![-128]));
+    }
+
+    #[test]
+    fn test_negative_unsigned() {
+        let err = "x=-5".all_ints::<usize>().unwrap_err();
+        assert_eq!(
+            err,
+            ScanError {
+                offset: 2,
+                kind: ScanErrorKind::Negative,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "at byte 2: negative integer for an unsigned type"
+        );
+        assert_eq!("-7".ints::<u32, 1>().unwrap_err().offset, 0);
+        assert_eq!("3 -4".all_ints::<u8>().unwrap_err().offset, 2);
+        assert_eq!("3-4".all_ints::<u8>(), Ok(vec![3, 4]));
+    }
+
+    #[test]
+    fn test_fixed_arity() {
+        let [a, b]: [usize; 2] = "47|53".ints().unwrap();
+        assert_eq!((a, b), (47, 53));
+        let err = "1 2 3".ints::<usize, 2>().unwrap_err();
+        assert_eq!(err.offset, 4);
+        assert_eq!(err.kind, ScanErrorKind::TooMany { expected: 2 });
+        let err = "x=1".ints::<usize, 2>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "at byte 3: expected 2 integers but found 1"
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let err = "7 300".all_ints::<u8>().unwrap_err();
+        assert_eq!(err.offset, 2);
+        assert_eq!(err.kind, ScanErrorKind::Overflow);
+    }
+}
@@ -0,0 +1,335 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+pub struct Search<N> {
+    starts: HashSet<N>,
+    dist: HashMap<N, usize>,
+    preds: HashMap<N, Vec<N>>,
+}
+
+impl<N: Copy + Eq + Hash> Search<N> {
+    fn new() -> Self {
+        Self {
+            starts: HashSet::new(),
+            dist: HashMap::new(),
+            preds: HashMap::new(),
+        }
+    }
+
+    pub fn dist(&self, node: N) -> Option<usize> {
+        self.dist.get(&node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, usize> {
+        &self.dist
+    }
+
+    pub fn predecessors(&self, node: N) -> &[N] {
+        self.preds.get(&node).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn path(&self, to: N) -> Option<Vec<N>> {
+        self.dist(to)?;
+        let mut path = vec![to];
+        while !self.starts.contains(path.last().unwrap()) {
+            path.push(self.predecessors(*path.last().unwrap())[0]);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn paths(&self, to: N) -> Vec<Vec<N>> {
+        if self.dist(to).is_none() {
+            return vec![];
+        }
+        // A start reached again by a zero-weight tie is still a path on its own.
+        let mut paths = vec![];
+        if self.starts.contains(&to) {
+            paths.push(vec![to]);
+        }
+        for &prev in self.predecessors(to) {
+            for mut path in self.paths(prev) {
+                path.push(to);
+                paths.push(path);
+            }
+        }
+        paths
+    }
+
+    // Whether `target` is `node` or one of its recorded ancestors. This walks every
+    // ancestor, so long zero-weight plateaus make `dijkstra` quadratic in their size.
+    fn descends_from(&self, node: N, target: N) -> bool {
+        let mut seen = HashSet::new();
+        let mut stack = vec![node];
+        while let Some(node) = stack.pop() {
+            if node == target {
+                return true;
+            }
+            if seen.insert(node) {
+                stack.extend_from_slice(self.predecessors(node));
+            }
+        }
+        false
+    }
+
+    pub fn path_nodes(&self, targets: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut stack: Vec<N> = targets
+            .into_iter()
+            .filter(|&node| self.dist(node).is_some())
+            .collect();
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node) {
+                stack.extend_from_slice(self.predecessors(node));
+            }
+        }
+        nodes
+    }
+}
+
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(N) -> I,
+) -> Search<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        search.starts.insert(start);
+        if search.dist.insert(start, 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let d = search.dist[&node] + 1;
+        for next in neighbors(node) {
+            match search.dist.get(&next) {
+                None => {
+                    search.dist.insert(next, d);
+                    search.preds.insert(next, vec![node]);
+                    queue.push_back(next);
+                }
+                Some(&e) if e == d => search.preds.get_mut(&next).unwrap().push(node),
+                Some(_) => {}
+            }
+        }
+    }
+    search
+}
+
+pub fn dfs<N, I>(start: N, mut neighbors: impl FnMut(N) -> I) -> Vec<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = HashSet::new();
+    let mut order = vec![];
+    let mut stack = vec![start];
+    while let Some(node) = stack.pop() {
+        if visited.insert(node) {
+            order.push(node);
+            stack.extend(neighbors(node));
+        }
+    }
+    order
+}
+
+struct Entry<N> {
+    priority: usize,
+    cost: usize,
+    node: N,
+}
+
+impl<N> PartialEq for Entry<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for Entry<N> {}
+
+impl<N> PartialOrd for Entry<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Entry<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(N) -> I,
+) -> Search<N>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut search = Search::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        search.starts.insert(start);
+        search.dist.insert(start, 0);
+        heap.push(Entry {
+            priority: 0,
+            cost: 0,
+            node: start,
+        });
+    }
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        if search.dist[&node] < cost {
+            continue;
+        }
+        for (next, weight) in neighbors(node) {
+            let d = cost + weight;
+            match search.dist.get(&next) {
+                Some(&e) if e < d => {}
+                // A zero-weight tie back to an ancestor would make `paths` loop forever.
+                Some(&e) if e == d => {
+                    if weight > 0 || !search.descends_from(node, next) {
+                        search.preds.entry(next).or_default().push(node);
+                    }
+                }
+                _ => {
+                    search.dist.insert(next, d);
+                    search.preds.insert(next, vec![node]);
+                    heap.push(Entry {
+                        priority: d,
+                        cost: d,
+                        node: next,
+                    });
+                }
+            }
+        }
+    }
+    search
+}
+
+pub fn astar<N, I>(
+    start: N,
+    mut goal: impl FnMut(N) -> bool,
+    mut neighbors: impl FnMut(N) -> I,
+    mut heuristic: impl FnMut(N) -> usize,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut search = Search::new();
+    let mut heap = BinaryHeap::new();
+    search.starts.insert(start);
+    search.dist.insert(start, 0);
+    heap.push(Entry {
+        priority: heuristic(start),
+        cost: 0,
+        node: start,
+    });
+    while let Some(Entry { cost, node, .. }) = heap.pop() {
+        if search.dist[&node] < cost {
+            continue;
+        }
+        if goal(node) {
+            return Some((cost, search.path(node).unwrap()));
+        }
+        for (next, weight) in neighbors(node) {
+            let d = cost + weight;
+            if search.dist.get(&next).is_none_or(|&e| d < e) {
+                search.dist.insert(next, d);
+                search.preds.insert(next, vec![node]);
+                heap.push(Entry {
+                    priority: d + heuristic(next),
+                    cost: d,
+                    node: next,
+                });
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Point};
+
+    const MAZE: &str = "\
+S...#
+.##.#
+....E
+";
+
+    fn open(grid: &Grid<u8>, (x, y): Point) -> impl Iterator<Item = Point> + '_ {
+        grid.neighbors4(x, y)
+            .filter(|&(x, y)| grid.get(x, y) != Some(&b'#'))
+    }
+
+    #[test]
+    fn test_bfs_paths() {
+        let grid = Grid::parse(MAZE);
+        let search = bfs([(0, 0)], |p| open(&grid, p));
+        assert_eq!(search.dist((4, 2)), Some(6));
+        assert_eq!(search.dist((4, 0)), None);
+        assert_eq!(search.path((4, 2)).unwrap().len(), 7);
+        assert_eq!(search.paths((4, 2)).len(), 2);
+        assert_eq!(search.path_nodes([(4, 2)]).len(), 11);
+    }
+
+    #[test]
+    fn test_dfs_reachable() {
+        let grid = Grid::parse(MAZE);
+        assert_eq!(dfs((0, 0), |p| open(&grid, p)).len(), 11);
+    }
+
+    #[test]
+    fn test_weighted() {
+        let grid = Grid::parse(MAZE);
+        let weighted = |p| open(&grid, p).map(|q: Point| (q, 1 + q.1 as usize));
+        let search = dijkstra([(0, 0)], weighted);
+        assert_eq!(search.dist((4, 2)), Some(11));
+        assert_eq!(search.paths((4, 2)).len(), 1);
+        let (cost, path) = astar(
+            (0, 0),
+            |p| p == (4, 2),
+            weighted,
+            |(x, y)| x.abs_diff(4) + y.abs_diff(2),
+        )
+        .unwrap();
+        assert_eq!(cost, 11);
+        assert_eq!(path, search.path((4, 2)).unwrap());
+    }
+
+    #[test]
+    fn test_zero_weight_into_start() {
+        let search = dijkstra([0u32, 1], |n| if n == 0 { vec![(1, 0)] } else { vec![] });
+        assert_eq!(search.dist(1), Some(0));
+        assert_eq!(search.paths(1), [vec![1], vec![0, 1]]);
+        assert_eq!(search.path(1), Some(vec![1]));
+        let search = dijkstra([0u32], |n| [(1 - n, 0)]);
+        assert_eq!(search.paths(0), [vec![0]]);
+        assert_eq!(search.paths(1), [vec![0, 1]]);
+    }
+
+    #[test]
+    fn test_zero_weight_cycle() {
+        let search = dijkstra(['s'], |n| match n {
+            's' => vec![('a', 1), ('b', 1)],
+            'a' => vec![('b', 0)],
+            'b' => vec![('a', 0)],
+            _ => vec![],
+        });
+        assert_eq!(search.dist('b'), Some(1));
+        // Whichever of `a` and `b` is settled first becomes the other's extra predecessor.
+        let (a, b) = (search.paths('a'), search.paths('b'));
+        assert_eq!(a.len() + b.len(), 3);
+        assert!(a.contains(&vec!['s', 'a']) && b.contains(&vec!['s', 'b']));
+    }
+}
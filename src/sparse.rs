@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use crate::grid::Point;
+
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    bounds: Option<(Point, Point)>,
+}
+
+fn extend(bounds: Option<(Point, Point)>, (x, y): Point) -> (Point, Point) {
+    match bounds {
+        None => ((x, y), (x, y)),
+        Some((min, max)) => ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y))),
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        self.cells.get(&(x, y))
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        self.cells.get_mut(&(x, y))
+    }
+
+    pub fn insert(&mut self, x: isize, y: isize, value: T) -> Option<T> {
+        self.bounds = Some(extend(self.bounds, (x, y)));
+        self.cells.insert((x, y), value)
+    }
+
+    pub fn remove(&mut self, x: isize, y: isize) -> Option<T> {
+        let value = self.cells.remove(&(x, y))?;
+        if let Some((min, max)) = self.bounds {
+            if x == min.0 || x == max.0 || y == min.1 || y == max.1 {
+                self.bounds = self
+                    .cells
+                    .keys()
+                    .fold(None, |bounds, &p| Some(extend(bounds, p)));
+            }
+        }
+        Some(value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(&p, v)| (p, v))
+    }
+
+    fn contains_bounds(&self, x: isize, y: isize) -> bool {
+        match self.bounds {
+            None => false,
+            Some((min, max)) => min.0 <= x && x <= max.0 && min.1 <= y && y <= max.1,
+        }
+    }
+
+    pub fn neighbors4(&self, x: isize, y: isize) -> impl Iterator<Item = Point> {
+        [(1, 0), (0, -1), (-1, 0), (0, 1)]
+            .into_iter()
+            .map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    pub fn neighbors8(&self, x: isize, y: isize) -> impl Iterator<Item = Point> {
+        [
+            (1, 0),
+            (1, -1),
+            (0, -1),
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, 1),
+            (1, 1),
+        ]
+        .into_iter()
+        .map(move |(dx, dy)| (x + dx, y + dy))
+    }
+
+    pub fn ray(
+        &self,
+        x: isize,
+        y: isize,
+        dx: isize,
+        dy: isize,
+    ) -> impl Iterator<Item = Point> + '_ {
+        assert_ne!((dx, dy), (0, 0));
+        (1..)
+            .map(move |k| (x + k * dx, y + k * dy))
+            .take_while(|&(x, y)| self.contains_bounds(x, y))
+    }
+
+    pub fn render(&self, mut f: impl FnMut(Option<&T>) -> char) -> String {
+        let mut s = String::new();
+        if let Some((min, max)) = self.bounds {
+            for y in min.1..=max.1 {
+                for x in min.0..=max.0 {
+                    s.push(f(self.get(x, y)));
+                }
+                s.push('\n');
+            }
+        }
+        s
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for ((x, y), value) in iter {
+            grid.insert(x, y, value);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounds_and_render() {
+        let mut grid: SparseGrid<char> = [((-2, 1), 'a'), ((1, -1), 'b'), ((0, 0), 'c')]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.bounds(), Some(((-2, -1), (1, 1))));
+        assert_eq!(grid.render(|c| *c.unwrap_or(&'.')), "...b\n..c.\na...\n");
+        assert_eq!(grid.ray(0, 0, -1, 1).collect::<Vec<_>>(), [(-1, 1)]);
+        grid.remove(-2, 1);
+        assert_eq!(grid.bounds(), Some(((0, -1), (1, 0))));
+        grid.insert(1_000_000, 0, 'd');
+        assert_eq!(grid.bounds(), Some(((0, -1), (1_000_000, 0))));
+    }
+}
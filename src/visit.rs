@@ -0,0 +1,169 @@
+use enumset::{EnumSet, EnumSetType};
+
+fn index(width: usize, height: usize, x: isize, y: isize) -> Option<usize> {
+    let i: usize = y.try_into().ok()?;
+    let j: usize = x.try_into().ok()?;
+    if i >= height || j >= width {
+        return None;
+    }
+    Some(i * width + j)
+}
+
+pub struct Visited {
+    width: usize,
+    height: usize,
+    words: Vec<u64>,
+    stamps: Vec<u32>,
+    generation: u32,
+}
+
+impl Visited {
+    pub fn new(width: usize, height: usize) -> Self {
+        let n = (width * height).div_ceil(64);
+        Self {
+            width,
+            height,
+            words: vec![0; n],
+            stamps: vec![0; n],
+            generation: 1,
+        }
+    }
+
+    // A flat set of indices `0..len`, for when the keys aren't grid points.
+    pub fn with_len(len: usize) -> Self {
+        Self::new(len, 1)
+    }
+
+    pub fn clear(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+        if self.generation == 0 {
+            self.stamps.fill(0);
+            self.generation = 1;
+        }
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        index(self.width, self.height, x, y).is_some_and(|k| self.contains_index(k))
+    }
+
+    pub fn insert(&mut self, x: isize, y: isize) -> bool {
+        self.insert_index(index(self.width, self.height, x, y).unwrap())
+    }
+
+    pub fn contains_index(&self, k: usize) -> bool {
+        let w = k / 64;
+        k < self.width * self.height
+            && self.stamps[w] == self.generation
+            && self.words[w] & (1 << (k % 64)) != 0
+    }
+
+    pub fn insert_index(&mut self, k: usize) -> bool {
+        assert!(k < self.width * self.height, "index {k} out of range");
+        let w = k / 64;
+        if self.stamps[w] != self.generation {
+            self.stamps[w] = self.generation;
+            self.words[w] = 0;
+        }
+        let bit = 1 << (k % 64);
+        let fresh = self.words[w] & bit == 0;
+        self.words[w] |= bit;
+        fresh
+    }
+}
+
+pub struct DirectedVisits<D: EnumSetType> {
+    width: usize,
+    height: usize,
+    cells: Vec<(u32, EnumSet<D>)>,
+    generation: u32,
+}
+
+impl<D: EnumSetType> DirectedVisits<D> {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![(0, EnumSet::empty()); width * height],
+            generation: 1,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+        if self.generation == 0 {
+            self.cells.fill((0, EnumSet::empty()));
+            self.generation = 1;
+        }
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> EnumSet<D> {
+        match index(self.width, self.height, x, y) {
+            Some(k) if self.cells[k].0 == self.generation => self.cells[k].1,
+            _ => EnumSet::empty(),
+        }
+    }
+
+    pub fn insert(&mut self, x: isize, y: isize, direction: D) -> bool {
+        let k = index(self.width, self.height, x, y).unwrap();
+        let (stamp, directions) = &mut self.cells[k];
+        if *stamp != self.generation {
+            *stamp = self.generation;
+            *directions = EnumSet::empty();
+        }
+        directions.insert(direction)
+    }
+
+    pub fn count(&self) -> usize {
+        self.cells
+            .iter()
+            .filter(|&&(stamp, directions)| stamp == self.generation && !directions.is_empty())
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(EnumSetType)]
+    enum Axis {
+        Horizontal,
+        Vertical,
+    }
+
+    #[test]
+    fn test_visited_clear() {
+        let mut visited = Visited::new(10, 10);
+        assert!(visited.insert(3, 7));
+        assert!(!visited.insert(3, 7));
+        assert!(visited.contains(3, 7));
+        assert!(!visited.contains(7, 3));
+        assert!(!visited.contains(-1, 0));
+        visited.clear();
+        assert!(!visited.contains(3, 7));
+        assert!(visited.insert(3, 7));
+    }
+
+    #[test]
+    fn test_visited_indices() {
+        let mut visited = Visited::with_len(130321);
+        assert!(visited.insert_index(130320));
+        assert!(!visited.insert_index(130320));
+        assert!(visited.contains_index(130320));
+        assert!(!visited.contains_index(130321));
+        visited.clear();
+        assert!(!visited.contains_index(130320));
+    }
+
+    #[test]
+    fn test_directed_visits_clear() {
+        let mut visits = DirectedVisits::new(2, 2);
+        assert!(visits.insert(1, 0, Axis::Horizontal));
+        assert!(visits.insert(1, 0, Axis::Vertical));
+        assert!(!visits.insert(1, 0, Axis::Vertical));
+        assert_eq!(visits.count(), 1);
+        visits.clear();
+        assert!(visits.get(1, 0).is_empty());
+        assert_eq!(visits.count(), 0);
+    }
+}